@@ -10,20 +10,35 @@ fn main() {
     // Define the source directory
     let src_dir = Path::new("src");
 
-    // Recursively find all `.txt` files in `src/dayN`
-    let txt_files = find_txt_files(src_dir, src_dir).expect("Failed to find .txt files");
+    // Inputs are only compiled into the binary when the `embed-inputs` feature is enabled,
+    // otherwise they are loaded at runtime and the table stays empty
+    let mut table = String::from("&[\n");
 
-    for (src, rel_path) in txt_files {
-        let dest = out_path.join(rel_path);
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        // Recursively find all `.txt` files in `src/dayN`
+        let txt_files = find_txt_files(src_dir, src_dir).expect("Failed to find .txt files");
 
-        // Create parent directories in the destination path
-        if let Some(parent) = dest.parent() {
-            fs::create_dir_all(parent).expect("Failed to create destination directory");
+        for (src, rel_path) in txt_files {
+            let key = rel_path
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let abs_path = fs::canonicalize(&src).expect("Failed to resolve input path");
+
+            table.push_str(&format!("    ({key:?}, include_str!({abs_path:?})),\n"));
         }
 
-        // Copy the file
-        fs::copy(&src, &dest).expect("Failed to copy file");
+        // Pick up newly added input files
+        println!("cargo:rerun-if-changed=src");
+    } else {
+        println!("cargo:rerun-if-changed=build.rs");
     }
+
+    table.push_str("]\n");
+
+    fs::write(out_path.join("embedded_inputs.rs"), table)
+        .expect("Failed to write embedded inputs table");
 }
 
 /// Recursively finds all `.txt` files in the given directory, returning a vector
@@ -37,7 +52,7 @@ fn find_txt_files(base_dir: &Path, dir: &Path) -> std::io::Result<Vec<(PathBuf,
 
         if path.is_dir() {
             // Recurse into directories
-            txt_files.extend(find_txt_files(&base_dir, &path)?);
+            txt_files.extend(find_txt_files(base_dir, &path)?);
         } else if path.extension().and_then(|ext| ext.to_str()) == Some("txt") {
             // Collect `.txt` files
             let rel_path = path.strip_prefix(base_dir).unwrap().to_path_buf();
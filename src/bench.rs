@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use crate::{
+    runner::{format_duration, Align, Table},
+    solution::{Parts, Puzzle},
+    utils::parse::ParseError,
+};
+
+/// Summary statistics of repeated timings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Some(Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Timing statistics of one phase (`parse`, `1` or `2`) of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub day: u32,
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Runs the selected parts of a puzzle `warmup` times untimed and then `runs` times timed.
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: Parts,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<BenchResult>, ParseError> {
+    for _ in 0..warmup {
+        puzzle.run(input, parts)?;
+    }
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_one = Vec::with_capacity(runs);
+    let mut part_two = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let run = puzzle.run(input, parts)?;
+        parse.push(run.parse_duration);
+        part_one.extend(run.part_one.map(|part| part.duration));
+        part_two.extend(run.part_two.map(|part| part.duration));
+    }
+
+    let results = [("parse", parse), ("1", part_one), ("2", part_two)]
+        .into_iter()
+        .filter_map(|(phase, samples)| {
+            Stats::from_samples(&samples).map(|stats| BenchResult {
+                day: puzzle.day(),
+                phase: phase.to_string(),
+                stats,
+            })
+        })
+        .collect();
+
+    Ok(results)
+}
+
+const CSV_HEADER: &str = "day,phase,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+pub fn to_csv(results: &[BenchResult]) -> String {
+    let mut output = format!("{CSV_HEADER}\n");
+
+    for result in results {
+        let stats = &result.stats;
+        output.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            result.day,
+            result.phase,
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos(),
+        ));
+    }
+
+    output
+}
+
+pub fn parse_csv(text: &str) -> Result<Vec<BenchResult>, String> {
+    let mut lines = text.lines().enumerate();
+
+    match lines.next() {
+        Some((_, header)) if header.trim() == CSV_HEADER => {}
+        _ => return Err(format!("expected header `{CSV_HEADER}`")),
+    }
+
+    lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let error = || format!("line {}: invalid benchmark record", index + 1);
+            let fields: Vec<&str> = line.trim().split(',').collect();
+            let [day, phase, runs, min, median, mean, stddev] = fields[..] else {
+                return Err(error());
+            };
+            let nanos = |field: &str| field.parse().map(Duration::from_nanos).map_err(|_| error());
+
+            Ok(BenchResult {
+                day: day.parse().map_err(|_| error())?,
+                phase: phase.to_string(),
+                stats: Stats {
+                    runs: runs.parse().map_err(|_| error())?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    stddev: nanos(stddev)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Renders the results, with the change of the median against `baseline` when given.
+pub fn render(results: &[BenchResult], baseline: Option<&[BenchResult]>) -> String {
+    let mut table = Table::new([Align::Right; 8]);
+    table.row(
+        [
+            "Day", "Part", "Runs", "Min", "Median", "Mean", "Stddev", "Change",
+        ]
+        .map(String::from),
+    );
+    table.separator();
+
+    for result in results {
+        let stats = &result.stats;
+        let change = baseline
+            .and_then(|baseline| {
+                baseline
+                    .iter()
+                    .find(|b| b.day == result.day && b.phase == result.phase)
+            })
+            .map(|previous| format_change(previous.stats.median, stats.median))
+            .unwrap_or_default();
+
+        table.row([
+            result.day.to_string(),
+            result.phase.clone(),
+            stats.runs.to_string(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.stddev),
+            change,
+        ]);
+    }
+
+    table.render()
+}
+
+fn format_change(previous: Duration, current: Duration) -> String {
+    if previous.is_zero() {
+        return String::new();
+    }
+
+    let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1} %")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(8), ms(6)]).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let results = vec![BenchResult {
+            day: 6,
+            phase: "2".to_string(),
+            stats: Stats::from_samples(&[ms(3), ms(1), ms(2)]).unwrap(),
+        }];
+
+        assert_eq!(parse_csv(&to_csv(&results)), Ok(results));
+        assert!(parse_csv("day,phase\n").is_err());
+    }
+
+    #[test]
+    fn change_against_baseline() {
+        assert_eq!(format_change(ms(200), ms(150)), "-25.0 %");
+        assert_eq!(format_change(ms(100), ms(110)), "+10.0 %");
+    }
+}
@@ -0,0 +1,451 @@
+use std::path::PathBuf;
+
+use crate::solution::Parts;
+
+pub const USAGE: &str = "\
+Usage: aoc2024 [DAY|all] [OPTIONS]
+       aoc2024 verify [DAY|all] [--inputs-dir <DIR>] [--answers <PATH>]
+       aoc2024 bench [DAY|all] [--part <1|2|both>] [--runs <N>] [--warmup <N>]
+                     [--inputs-dir <DIR>] [--output <PATH>] [--compare <PATH>]
+       aoc2024 render DAY [--input <PATH>|--stdin|--example <N>] [--inputs-dir <DIR>]
+                      [--image <PATH>] [--scale <N>] [--plain]
+       aoc2024 new DAY [--src-dir <DIR>]
+
+Runs the solution of DAY, or of every implemented day when DAY is omitted or `all`.
+`verify` checks the answers against the recorded ones in `answers.txt`.
+`bench` runs the solutions repeatedly and reports timing statistics.
+`render` draws the input of DAY to the terminal, or to a PPM/PGM image with `--image`.
+`new` creates the module of DAY from the `day00` template and registers it.
+
+Options:
+  -p, --part <1|2|both>   Run only the given part (default: both)
+  -i, --input <PATH>      Read the puzzle input from PATH
+      --stdin             Read the puzzle input from standard input
+  -e, --example <N>       Use the bundled example file `testN.txt`
+      --inputs-dir <DIR>  Look up bundled inputs in DIR first (also set by AOC_INPUTS_DIR)
+      --answers <PATH>    Read recorded answers from PATH instead of `answers.txt`
+      --runs <N>          Number of timed benchmark runs (default: 10)
+      --warmup <N>        Number of untimed benchmark runs (default: 1)
+      --output <PATH>     Write benchmark results to PATH as CSV
+      --compare <PATH>    Compare benchmark medians with results in PATH
+      --image <PATH>      Write the drawing to PATH, as PGM if it ends in `.pgm`, else as PPM
+      --scale <N>         Pixels per cell in images (default: 4)
+      --plain             Draw to the terminal without colours
+      --src-dir <DIR>     Source directory to create new days in (default: `src`)
+  -h, --help              Print this message
+
+The input options can only be used together with a single DAY.";
+
+/// Where the puzzle input of a single day should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    Example(u32),
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        day: Option<u32>,
+        parts: Parts,
+        input: InputSource,
+        inputs_dir: Option<PathBuf>,
+    },
+    Verify {
+        day: Option<u32>,
+        inputs_dir: Option<PathBuf>,
+        answers: Option<PathBuf>,
+    },
+    Bench {
+        day: Option<u32>,
+        parts: Parts,
+        runs: usize,
+        warmup: usize,
+        inputs_dir: Option<PathBuf>,
+        output: Option<PathBuf>,
+        compare: Option<PathBuf>,
+    },
+    Render {
+        day: u32,
+        input: InputSource,
+        inputs_dir: Option<PathBuf>,
+        image: Option<PathBuf>,
+        scale: usize,
+        plain: bool,
+    },
+    New {
+        day: u32,
+        src_dir: Option<PathBuf>,
+    },
+    Help,
+}
+
+/// Parses the command-line arguments, excluding the program name.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("verify") => {
+            args.next();
+            parse_verify_args(args)
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench_args(args)
+        }
+        Some("render") => {
+            args.next();
+            parse_render_args(args)
+        }
+        Some("new") => {
+            args.next();
+            parse_new_args(args)
+        }
+        _ => parse_run_args(args),
+    }
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day: Option<u32> = None;
+    let mut parts = Parts::Both;
+    let mut input = InputSource::Default;
+    let mut inputs_dir: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-p" | "--part" => parts = parse_parts(&require_value(&mut args, &arg)?)?,
+            "-i" | "--input" => set_input(
+                &mut input,
+                InputSource::File(require_value(&mut args, &arg)?.into()),
+            )?,
+            "--stdin" => set_input(&mut input, InputSource::Stdin)?,
+            "-e" | "--example" => {
+                let value = require_value(&mut args, &arg)?;
+                let number = value
+                    .parse()
+                    .map_err(|_| format!("Invalid example number `{value}`"))?;
+                set_input(&mut input, InputSource::Example(number))?
+            }
+            "--inputs-dir" => inputs_dir = Some(require_value(&mut args, &arg)?.into()),
+            other => set_day(&mut day, other)?,
+        }
+    }
+
+    if day.is_none() && input != InputSource::Default {
+        return Err("Input options require a single day".to_string());
+    }
+
+    Ok(Command::Run {
+        day,
+        parts,
+        input,
+        inputs_dir,
+    })
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day: Option<u32> = None;
+    let mut inputs_dir: Option<PathBuf> = None;
+    let mut answers: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--inputs-dir" => inputs_dir = Some(require_value(&mut args, &arg)?.into()),
+            "--answers" => answers = Some(require_value(&mut args, &arg)?.into()),
+            other => set_day(&mut day, other)?,
+        }
+    }
+
+    Ok(Command::Verify {
+        day,
+        inputs_dir,
+        answers,
+    })
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day: Option<u32> = None;
+    let mut parts = Parts::Both;
+    let mut runs = 10;
+    let mut warmup = 1;
+    let mut inputs_dir: Option<PathBuf> = None;
+    let mut output: Option<PathBuf> = None;
+    let mut compare: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-p" | "--part" => parts = parse_parts(&require_value(&mut args, &arg)?)?,
+            "--runs" => runs = parse_count(&require_value(&mut args, &arg)?, &arg)?,
+            "--warmup" => warmup = parse_count(&require_value(&mut args, &arg)?, &arg)?,
+            "--inputs-dir" => inputs_dir = Some(require_value(&mut args, &arg)?.into()),
+            "--output" => output = Some(require_value(&mut args, &arg)?.into()),
+            "--compare" => compare = Some(require_value(&mut args, &arg)?.into()),
+            other => set_day(&mut day, other)?,
+        }
+    }
+
+    if runs == 0 {
+        return Err("At least one benchmark run is required".to_string());
+    }
+
+    Ok(Command::Bench {
+        day,
+        parts,
+        runs,
+        warmup,
+        inputs_dir,
+        output,
+        compare,
+    })
+}
+
+fn parse_render_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day: Option<u32> = None;
+    let mut input = InputSource::Default;
+    let mut inputs_dir: Option<PathBuf> = None;
+    let mut image: Option<PathBuf> = None;
+    let mut scale = 4;
+    let mut plain = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-i" | "--input" => set_input(
+                &mut input,
+                InputSource::File(require_value(&mut args, &arg)?.into()),
+            )?,
+            "--stdin" => set_input(&mut input, InputSource::Stdin)?,
+            "-e" | "--example" => {
+                let value = require_value(&mut args, &arg)?;
+                let number = value
+                    .parse()
+                    .map_err(|_| format!("Invalid example number `{value}`"))?;
+                set_input(&mut input, InputSource::Example(number))?
+            }
+            "--inputs-dir" => inputs_dir = Some(require_value(&mut args, &arg)?.into()),
+            "--image" => image = Some(require_value(&mut args, &arg)?.into()),
+            "--scale" => scale = parse_count(&require_value(&mut args, &arg)?, &arg)?,
+            "--plain" => plain = true,
+            other => set_day(&mut day, other)?,
+        }
+    }
+
+    if scale == 0 {
+        return Err("The image scale must be at least 1".to_string());
+    }
+
+    Ok(Command::Render {
+        day: day.ok_or("Missing day to render")?,
+        input,
+        inputs_dir,
+        image,
+        scale,
+        plain,
+    })
+}
+
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day: Option<u32> = None;
+    let mut src_dir: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--src-dir" => src_dir = Some(require_value(&mut args, &arg)?.into()),
+            other => set_day(&mut day, other)?,
+        }
+    }
+
+    Ok(Command::New {
+        day: day.ok_or("Missing day to create")?,
+        src_dir,
+    })
+}
+
+fn parse_parts(value: &str) -> Result<Parts, String> {
+    match value {
+        "1" => Ok(Parts::One),
+        "2" => Ok(Parts::Two),
+        "both" => Ok(Parts::Both),
+        other => Err(format!("Invalid part `{other}`, expected 1, 2 or both")),
+    }
+}
+
+fn parse_count(value: &str, option: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid count `{value}` for `{option}`"))
+}
+
+/// Handles a positional argument, which selects a single day unless it is `all`.
+fn set_day(day: &mut Option<u32>, arg: &str) -> Result<(), String> {
+    if arg.starts_with('-') {
+        return Err(format!("Unknown option `{arg}`"));
+    }
+
+    if day.is_some() {
+        return Err(format!("Unexpected argument `{arg}`"));
+    }
+
+    if arg != "all" {
+        *day = Some(
+            arg.parse()
+                .map_err(|_| format!("Invalid day number `{arg}`"))?,
+        );
+    }
+
+    Ok(())
+}
+
+fn require_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("Missing value for `{option}`"))
+}
+
+fn set_input(input: &mut InputSource, source: InputSource) -> Result<(), String> {
+    if *input != InputSource::Default {
+        return Err("Only one of --input, --stdin and --example can be given".to_string());
+    }
+
+    *input = source;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_runs_all_days() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Command::Run {
+                day: None,
+                parts: Parts::Both,
+                input: InputSource::Default,
+                inputs_dir: None,
+            })
+        );
+        assert_eq!(parse(&["all"]), parse(&[]));
+    }
+
+    #[test]
+    fn day_with_part_and_example() {
+        assert_eq!(
+            parse(&["6", "--part", "2", "-e", "1"]),
+            Ok(Command::Run {
+                day: Some(6),
+                parts: Parts::Two,
+                input: InputSource::Example(1),
+                inputs_dir: None,
+            })
+        );
+    }
+
+    #[test]
+    fn day_with_input_file() {
+        assert_eq!(
+            parse(&["-i", "my_input.txt", "17"]),
+            Ok(Command::Run {
+                day: Some(17),
+                parts: Parts::Both,
+                input: InputSource::File("my_input.txt".into()),
+                inputs_dir: None,
+            })
+        );
+    }
+
+    #[test]
+    fn all_days_with_inputs_dir() {
+        assert_eq!(
+            parse(&["--inputs-dir", "/tmp/inputs"]),
+            Ok(Command::Run {
+                day: None,
+                parts: Parts::Both,
+                input: InputSource::Default,
+                inputs_dir: Some("/tmp/inputs".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn verify_with_answers_file() {
+        assert_eq!(
+            parse(&["verify", "5", "--answers", "answers.txt"]),
+            Ok(Command::Verify {
+                day: Some(5),
+                inputs_dir: None,
+                answers: Some("answers.txt".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn bench_with_runs_and_output() {
+        assert_eq!(
+            parse(&["bench", "6", "-p", "2", "--runs", "5", "--output", "out.csv"]),
+            Ok(Command::Bench {
+                day: Some(6),
+                parts: Parts::Two,
+                runs: 5,
+                warmup: 1,
+                inputs_dir: None,
+                output: Some("out.csv".into()),
+                compare: None,
+            })
+        );
+    }
+
+    #[test]
+    fn render_to_image() {
+        assert_eq!(
+            parse(&["render", "6", "--image", "6.ppm", "--scale", "8"]),
+            Ok(Command::Render {
+                day: 6,
+                input: InputSource::Default,
+                inputs_dir: None,
+                image: Some("6.ppm".into()),
+                scale: 8,
+                plain: false,
+            })
+        );
+    }
+
+    #[test]
+    fn new_day() {
+        assert_eq!(
+            parse(&["new", "8"]),
+            Ok(Command::New {
+                day: 8,
+                src_dir: None,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        assert!(parse(&["x"]).is_err());
+        assert!(parse(&["1", "2"]).is_err());
+        assert!(parse(&["1", "--part", "3"]).is_err());
+        assert!(parse(&["1", "--part"]).is_err());
+        assert!(parse(&["1", "--stdin", "-e", "1"]).is_err());
+        assert!(parse(&["--stdin"]).is_err());
+        assert!(parse(&["1", "--verbose"]).is_err());
+        assert!(parse(&["verify", "1", "--part", "1"]).is_err());
+        assert!(parse(&["bench", "1", "--runs", "0"]).is_err());
+        assert!(parse(&["bench", "1", "--runs", "many"]).is_err());
+        assert!(parse(&["render"]).is_err());
+        assert!(parse(&["render", "6", "--scale", "0"]).is_err());
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "all"]).is_err());
+    }
+}
@@ -1,12 +1,40 @@
-pub fn solve_first(input: &str) -> usize {
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::ParseError,
+};
+
+pub struct Day0N;
+
+impl Solution for Day0N {
+    type Input = ();
+
+    const DAY: u32 = 0;
+    const NAME: &'static str = "";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub fn solve_first(_input: &()) -> usize {
     42
 }
 
-pub fn solve_second(input: &str) -> usize {
+pub fn solve_second(_input: &()) -> usize {
     420
 }
 
-fn parse_input(input: &str) -> () {}
+fn parse_input(_input: &str) -> Result<(), ParseError> {
+    Ok(())
+}
 
 #[cfg(test)]
 mod tests {
@@ -16,11 +44,11 @@ mod tests {
 
     #[test]
     fn test_first() {
-        assert_eq!(solve_first(&read_input_file("day0N/test1.txt")), 42);
+        assert_eq!(solve_first(&parse_input(&read_input_file("day0N/test1.txt")).unwrap()), 42);
     }
 
     #[test]
     fn test_second() {
-        assert_eq!(solve_second(&read_input_file("day0N/test1.txt")), 420);
+        assert_eq!(solve_second(&parse_input(&read_input_file("day0N/test1.txt")).unwrap()), 420);
     }
 }
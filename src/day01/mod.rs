@@ -1,7 +1,33 @@
 use std::collections::HashMap;
 
-pub fn solve_first(input: &str) -> i32 {
-    let (mut left, mut right) = get_nums(input);
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::{whitespace_list, ParseError},
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    const DAY: u32 = 1;
+    const NAME: &'static str = "Historian Hysteria";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_nums(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub fn solve_first((left, right): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (mut left, mut right) = (left.clone(), right.clone());
 
     left.sort();
     right.sort();
@@ -14,32 +40,56 @@ pub fn solve_first(input: &str) -> i32 {
     total_diff
 }
 
-pub fn solve_second(input: &str) -> i32 {
-    let (left, right) = get_nums(input);
+pub fn solve_second((left, right): &(Vec<i32>, Vec<i32>)) -> i32 {
     let mut histogram: HashMap<i32, i32> = HashMap::new();
 
     for num in right {
-        *histogram.entry(num).or_insert(0) += 1;
+        *histogram.entry(*num).or_insert(0) += 1;
     }
 
     let mut result = 0;
 
     for num in left {
-        result += num * histogram.get(&num).cloned().unwrap_or(0)
+        result += num * histogram.get(num).cloned().unwrap_or(0)
     }
 
     result
 }
 
-fn get_nums(input: &str) -> (Vec<i32>, Vec<i32>) {
+fn get_nums(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
     let mut left: Vec<i32> = Vec::new();
     let mut right: Vec<i32> = Vec::new();
 
-    for line in input.lines() {
-        let mut nums = line.split_whitespace().map(|x| x.parse::<i32>().unwrap());
-        left.push(nums.next().unwrap());
-        right.push(nums.next().unwrap());
+    for (y, line) in input.lines().enumerate() {
+        match whitespace_list::<i32>(input, line)?[..] {
+            [a, b] => {
+                left.push(a);
+                right.push(b);
+            }
+            _ => return Err(ParseError::at(y, 0, "expected two numbers")),
+        }
     }
 
-    (left, right)
+    Ok((left, right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_nums_reports_invalid_number() {
+        assert_eq!(
+            get_nums("3   4\n4   x3\n"),
+            Err(ParseError::at(1, 4, "invalid value `x3`"))
+        );
+        assert_eq!(
+            get_nums("3   4\n4\n"),
+            Err(ParseError::at(1, 0, "expected two numbers"))
+        );
+        assert_eq!(
+            get_nums("3 x 4\n5 6\n"),
+            Err(ParseError::at(0, 2, "invalid value `x`"))
+        );
+    }
 }
@@ -1,16 +1,41 @@
-use crate::utils::drop_element;
+use crate::{
+    solution::{Answer, Solution},
+    utils::{
+        drop_element,
+        parse::{whitespace_list, ParseError},
+    },
+};
 
-pub fn solve_first(input: &str) -> usize {
-    let reports = get_reports(input);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+
+    const DAY: u32 = 2;
+    const NAME: &'static str = "Red-Nosed Reports";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_reports(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub fn solve_first(reports: &[Vec<i32>]) -> usize {
     reports.iter().filter(|r| is_report_valid(r, 0)).count()
 }
 
-pub fn solve_second(input: &str) -> usize {
-    let reports = get_reports(input);
+pub fn solve_second(reports: &[Vec<i32>]) -> usize {
     reports.iter().filter(|r| is_report_valid(r, 1)).count()
 }
 
-fn is_report_valid(report: &Vec<i32>, max_errors: i32) -> bool {
+fn is_report_valid(report: &[i32], max_errors: i32) -> bool {
     check_report(report, is_valid_increase, max_errors)
         || check_report(report, is_valid_decrease, max_errors)
 }
@@ -22,10 +47,10 @@ fn is_valid_increase(a: i32, b: i32) -> bool {
 
 fn is_valid_decrease(a: i32, b: i32) -> bool {
     let diff = b - a;
-    diff < 0 && diff >= -3
+    (-3..0).contains(&diff)
 }
 
-fn check_report(report: &Vec<i32>, condition: fn(i32, i32) -> bool, errors_left: i32) -> bool {
+fn check_report(report: &[i32], condition: fn(i32, i32) -> bool, errors_left: i32) -> bool {
     let first_error = (0..report.len() - 1).find(|i| !condition(report[*i], report[*i + 1]));
 
     match (first_error, errors_left) {
@@ -40,9 +65,37 @@ fn check_report(report: &Vec<i32>, condition: fn(i32, i32) -> bool, errors_left:
     }
 }
 
-fn get_reports(input: &str) -> Vec<Vec<i32>> {
+fn get_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     input
         .lines()
-        .map(|line| line.split(' ').map(|c| c.parse::<i32>().unwrap()).collect())
+        .enumerate()
+        .map(|(y, line)| {
+            let report: Vec<i32> = whitespace_list(input, line)?;
+            if report.is_empty() {
+                return Err(ParseError::at(y, 0, "expected a report"));
+            }
+            Ok(report)
+        })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_reports_rejects_invalid_lines() {
+        assert_eq!(
+            get_reports("7 6 4\n1 2 7\n"),
+            Ok(vec![vec![7, 6, 4], vec![1, 2, 7]])
+        );
+        assert_eq!(
+            get_reports("  a b\n"),
+            Err(ParseError::at(0, 2, "invalid value `a`"))
+        );
+        assert_eq!(
+            get_reports("7 6 4\n\n1 2 7\n"),
+            Err(ParseError::at(1, 0, "expected a report"))
+        );
+    }
+}
@@ -1,14 +1,40 @@
 use regex::Regex;
 
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::ParseError,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    const DAY: u32 = 3;
+    const NAME: &'static str = "Mull It Over";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
 pub fn solve_first(input: &str) -> usize {
-    find_and_sum_muls(&input)
+    find_and_sum_muls(input)
 }
 
 pub fn solve_second(input: &str) -> usize {
     input
         .split("do()")
         .map(|part| part.split_once("don't()").map_or(part, |(first, _)| first))
-        .map(|part| find_and_sum_muls(part))
+        .map(find_and_sum_muls)
         .sum()
 }
 
@@ -16,7 +42,7 @@ fn find_and_sum_muls(input: &str) -> usize {
     let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
     let mut total: usize = 0;
 
-    for captures in regex.captures_iter(&input) {
+    for captures in regex.captures_iter(input) {
         let a: usize = captures[1].parse().unwrap();
         let b: usize = captures[2].parse().unwrap();
         total += a * b;
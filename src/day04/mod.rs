@@ -1,81 +1,47 @@
-use crate::utils::grid::{Grid, GridDirection};
+use crate::{
+    solution::{Answer, Solution},
+    utils::{
+        grid::{Grid, Pattern},
+        parse::{grid, ParseError},
+    },
+};
 
-pub fn solve_first(input: &str) -> usize {
-    let grid = parse_input(input);
+pub struct Day04;
 
-    let mut passes = vec![
-        // Corner passes
-        grid.iter(0, 0, GridDirection::East),
-        grid.iter(grid.width - 1, 0, GridDirection::West),
-        grid.iter(0, grid.height - 1, GridDirection::East),
-        grid.iter(grid.width - 1, grid.height - 1, GridDirection::West),
-    ];
+impl Solution for Day04 {
+    type Input = Grid<char>;
 
-    // Left column passes
-    for y in 1..grid.height - 1 {
-        passes.push(grid.iter(0, y, GridDirection::East));
-        passes.push(grid.iter(0, y, GridDirection::SouthEast));
-        passes.push(grid.iter(0, y, GridDirection::NorthEast));
-    }
+    const DAY: u32 = 4;
+    const NAME: &'static str = "Ceres Search";
 
-    // Right column passes
-    for y in 1..grid.height - 1 {
-        passes.push(grid.iter(grid.width - 1, y, GridDirection::West));
-        passes.push(grid.iter(grid.width - 1, y, GridDirection::NorthWest));
-        passes.push(grid.iter(grid.width - 1, y, GridDirection::SouthWest));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
 
-    // Top row passes
-    for x in 0..grid.width {
-        passes.push(grid.iter(x, 0, GridDirection::South));
-        passes.push(grid.iter(x, 0, GridDirection::SouthEast));
-        passes.push(grid.iter(x, 0, GridDirection::SouthWest));
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
     }
 
-    // Bottom row passes
-    for x in 0..grid.width {
-        passes.push(grid.iter(x, grid.height - 1, GridDirection::North));
-        passes.push(grid.iter(x, grid.height - 1, GridDirection::NorthWest));
-        passes.push(grid.iter(x, grid.height - 1, GridDirection::NorthEast));
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
     }
+}
 
-    passes
-        .into_iter()
-        .map(|pass| pass.collect::<String>().matches("XMAS").count())
+pub fn solve_first(grid: &Grid<char>) -> usize {
+    grid.all_lines()
+        .map(|line| line.map(|(_, c)| c).collect::<String>().matches("XMAS").count())
         .sum()
 }
 
-pub fn solve_second(input: &str) -> usize {
-    let grid = parse_input(input);
-    let mut result = 0;
-
-    for x in 0..grid.width - 2 {
-        for y in 0..grid.height - 2 {
-            let view = grid.view(x, y, 3, 3);
-            let passes = [
-                view.iter(0, 0, GridDirection::SouthEast),
-                view.iter(2, 2, GridDirection::NorthWest),
-                view.iter(0, 2, GridDirection::NorthEast),
-                view.iter(2, 0, GridDirection::SouthWest),
-            ];
-
-            if passes
-                .into_iter()
-                .map(|pass| pass.collect::<String>().matches("MAS").count())
-                .sum::<usize>()
-                == 2
-            {
-                result += 1;
-            }
-        }
-    }
+pub fn solve_second(grid: &Grid<char>) -> usize {
+    let x_mas = Grid::from_lines(["M.S", ".A.", "M.S"].map(|l| l.chars().collect()).to_vec());
+    let pattern = Pattern::new(x_mas, '.').with_symmetries();
 
-    result
+    grid.find_pattern(&pattern).len()
 }
 
-fn parse_input(input: &str) -> Grid<char> {
-    let lines: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
-    Grid::from_lines(lines)
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    grid(input)
 }
 
 #[cfg(test)]
@@ -86,11 +52,11 @@ mod tests {
 
     #[test]
     fn test_first() {
-        assert_eq!(solve_first(&read_input_file("day04/test1.txt")), 18);
+        assert_eq!(solve_first(&parse_input(&read_input_file("day04/test1.txt")).unwrap()), 18);
     }
 
     #[test]
     fn test_second() {
-        assert_eq!(solve_second(&read_input_file("day04/test2.txt")), 9);
+        assert_eq!(solve_second(&parse_input(&read_input_file("day04/test2.txt")).unwrap()), 9);
     }
 }
@@ -1,7 +1,34 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-pub fn solve_first(input: &str) -> usize {
-    let (rule_map, updates) = parse_input(input);
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::{comma_list, parse_token, sections, ParseError},
+};
+
+pub struct Day05;
+
+type Rules = HashMap<u32, Vec<u32>>;
+
+impl Solution for Day05 {
+    type Input = (Rules, Vec<Vec<u32>>);
+
+    const DAY: u32 = 5;
+    const NAME: &'static str = "Print Queue";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub fn solve_first((rule_map, updates): &(Rules, Vec<Vec<u32>>)) -> usize {
     let empty_rules = Vec::<u32>::new();
 
     let valid_updates = updates.iter().filter(|update| {
@@ -23,8 +50,7 @@ pub fn solve_first(input: &str) -> usize {
     mid_elements.sum::<u32>() as usize
 }
 
-pub fn solve_second(input: &str) -> usize {
-    let (rule_map, updates) = parse_input(input);
+pub fn solve_second((rule_map, updates): &(Rules, Vec<Vec<u32>>)) -> usize {
     let empty_rules = Vec::<u32>::new();
 
     let invalid_updates = updates.iter().filter(|update| {
@@ -45,7 +71,7 @@ pub fn solve_second(input: &str) -> usize {
     let fixed_updates: Vec<Vec<u32>> = invalid_updates.map(|update| { 
         let mut fixed = update.clone();
         fixed.sort_by(|a, b| {
-            let rules = rule_map.get(&a).unwrap_or(&empty_rules);
+            let rules = rule_map.get(a).unwrap_or(&empty_rules);
             if rules.contains(b) { Ordering::Less } else { Ordering::Greater }
         });
         fixed
@@ -55,33 +81,38 @@ pub fn solve_second(input: &str) -> usize {
     mid_elements.sum::<u32>() as usize
 }
 
-fn parse_input(input: &str) -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
-    let (rule_part, update_part) = input.split_once("\n\n").unwrap();
-    let rules = parse_rules(rule_part);
-    let updates = parse_updates(update_part);
-
-    (rules, updates)
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<u32>>), ParseError> {
+    let [rule_part, update_part] = sections(input)[..] else {
+        return Err(ParseError::at_end(
+            input,
+            "expected a blank line between rules and updates",
+        ));
+    };
+    let rules = parse_rules(input, rule_part)?;
+    let updates = parse_updates(input, update_part)?;
+
+    Ok((rules, updates))
 }
 
-fn parse_rules(input: &str) -> HashMap<u32, Vec<u32>> {
+fn parse_rules(input: &str, rule_part: &str) -> Result<Rules, ParseError> {
     let mut rule_map = HashMap::new();
 
-    for line in input.lines() {
+    for line in rule_part.lines() {
         let (left, right) = line
             .split_once("|")
-            .map(|(a, b)| (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap()))
-            .unwrap();
+            .ok_or_else(|| ParseError::at_fragment(input, line, "expected `left|right` rule"))?;
+        let (left, right) = (parse_token(input, left)?, parse_token(input, right)?);
 
         rule_map.entry(left).or_insert_with(Vec::new).push(right);
     }
 
-    rule_map
+    Ok(rule_map)
 }
 
-fn parse_updates(input: &str) -> Vec<Vec<u32>> {
-    input
-        .split_whitespace()
-        .map(|line| line.split(",").map(|tok| tok.parse().unwrap()).collect())
+fn parse_updates(input: &str, update_part: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    update_part
+        .lines()
+        .map(|line| comma_list(input, line))
         .collect()
 }
 
@@ -93,11 +124,33 @@ mod tests {
 
     #[test]
     fn test_first() {
-        assert_eq!(solve_first(&read_input_file("day05/test1.txt")), 143);
+        assert_eq!(
+            solve_first(&parse_input(&read_input_file("day05/test1.txt")).unwrap()),
+            143
+        );
+    }
+
+    #[test]
+    fn parse_input_reports_invalid_rule() {
+        assert_eq!(
+            parse_input("47|53\n97-13\n\n75,47\n"),
+            Err(ParseError::at(1, 0, "expected `left|right` rule"))
+        );
+        assert_eq!(
+            parse_input("47|53\n75,47\n"),
+            Err(ParseError::at(
+                1,
+                5,
+                "expected a blank line between rules and updates"
+            ))
+        );
     }
 
     #[test]
     fn test_second() {
-        assert_eq!(solve_second(&read_input_file("day05/test1.txt")), 123);
+        assert_eq!(
+            solve_second(&parse_input(&read_input_file("day05/test1.txt")).unwrap()),
+            123
+        );
     }
 }
@@ -1,15 +1,47 @@
-use std::{collections::HashSet, fmt};
+use std::fmt;
 
-use crate::utils::grid::{Coords, Grid, GridDirection};
+use crate::{
+    solution::{Answer, Solution},
+    utils::{
+        grid::{BitGrid, Coords, DirectionBitGrid, Grid, GridDirection},
+        parse::{grid_with, ParseError},
+        render::{Colour, Glyph, Picture, Renderer},
+    },
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Grid<Tile>, Walker);
+
+    const DAY: u32 = 6;
+    const NAME: &'static str = "Guard Gallivant";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+
+    fn render(input: &Self::Input) -> Option<Picture> {
+        Some(draw(input).picture())
+    }
+}
 
 #[derive(Clone)]
-enum Tile {
+pub enum Tile {
     Empty,
     Wall,
 }
 
 #[derive(Clone)]
-struct Walker {
+pub struct Walker {
     position: Coords,
     direction: GridDirection,
 }
@@ -17,113 +49,147 @@ struct Walker {
 #[derive(Debug)]
 struct LoopDetected;
 
-pub fn solve_first(input: &str) -> usize {
-    let (grid, walker) = parse_input(input);
-    let visited_coords = simulate(&grid, walker).expect("Initial simulation stuck in loop");
-    visited_coords.len()
+pub fn solve_first((grid, walker): &(Grid<Tile>, Walker)) -> usize {
+    let mut visits = Visits::new(grid);
+    simulate(grid, walker.clone(), &mut visits).expect("Initial simulation stuck in loop");
+    visits.cells.count_ones()
 }
 
-pub fn solve_second(input: &str) -> usize {
-    let (mut grid, original_walker) = parse_input(input);
+pub fn solve_second(input: &(Grid<Tile>, Walker)) -> usize {
+    loop_obstructions(input).len()
+}
+
+/// Positions where a single added obstruction makes the walker loop.
+fn loop_obstructions((grid, original_walker): &(Grid<Tile>, Walker)) -> Vec<Coords> {
+    let mut grid = grid.clone();
+    let mut visits = Visits::new(&grid);
 
     // Get all visited non-start positions that candidates for obstruction placement
-    let mut visited_coords = simulate(&grid, original_walker.clone())
+    simulate(&grid, original_walker.clone(), &mut visits)
         .expect("Initial simulation stuck in loop");
 
-    visited_coords.remove(&original_walker.position);
+    let mut candidates = visits.cells.clone();
+    candidates.set_by_coords(&original_walker.position, false);
 
-    let mut result = 0;
+    let mut result = Vec::new();
 
     // Try each possible obstruction position
     // Run simulation until either walker leaves grid,
     // or hits the obstruction twice from the same direction
-    for obstruction_coords in visited_coords {
-        grid.set_by_coords(&obstruction_coords, Tile::Wall);
+    for obstruction_coords in candidates.iter_ones() {
+        *grid.get_mut_by_coords(&obstruction_coords) = Tile::Wall;
 
-        match simulate(&grid, original_walker.clone()) {
+        match simulate(&grid, original_walker.clone(), &mut visits) {
             Ok(_) => {}
-            Err(LoopDetected) => result += 1,
+            Err(LoopDetected) => result.push(obstruction_coords),
         }
 
-        grid.set_by_coords(&obstruction_coords, Tile::Empty);
+        *grid.get_mut_by_coords(&obstruction_coords) = Tile::Empty;
     }
 
     result
 }
 
-fn simulate(grid: &Grid<Tile>, mut walker: Walker) -> Result<HashSet<Coords>, LoopDetected> {
-    let mut wall_hit_directions = HashSet::<(Coords, GridDirection)>::new();
-    let mut visited_coords = HashSet::<Coords>::new();
-    visited_coords.insert(walker.position.clone());
+/// Draws the map with the cells the walker visits, its start and the obstructions that make
+/// it loop. When the walker already loops on the map, the obstructions are left out.
+fn draw(input: &(Grid<Tile>, Walker)) -> Renderer<'_, Tile> {
+    let (grid, walker) = input;
+    let mut renderer = Renderer::new(grid, |tile| match tile {
+        Tile::Empty => Glyph::plain('.'),
+        Tile::Wall => Glyph::coloured('#', Colour::Blue),
+    });
+
+    let mut visits = Visits::new(grid);
+    let outcome = simulate(grid, walker.clone(), &mut visits);
+
+    renderer.layer(
+        visits.cells.iter_ones(),
+        Glyph::coloured('X', Colour::Yellow),
+    );
+    if outcome.is_ok() {
+        renderer.layer(loop_obstructions(input), Glyph::coloured('O', Colour::Red));
+    }
+    renderer.layer([walker.position], Glyph::coloured('S', Colour::Green));
+    renderer
+}
+
+/// Cells visited by the walker and the directions it hit walls from,
+/// kept between simulations to reuse the allocations.
+struct Visits {
+    cells: BitGrid,
+    wall_hits: DirectionBitGrid,
+}
+
+impl Visits {
+    fn new(grid: &Grid<Tile>) -> Self {
+        let (width, height) = (grid.width as usize, grid.height as usize);
+        Visits {
+            cells: BitGrid::new(width, height),
+            wall_hits: DirectionBitGrid::new(width, height),
+        }
+    }
+}
+
+fn simulate(
+    grid: &Grid<Tile>,
+    mut walker: Walker,
+    visits: &mut Visits,
+) -> Result<(), LoopDetected> {
+    visits.cells.clear();
+    visits.wall_hits.clear();
+    visits.cells.insert(&walker.position);
 
     while let Some(coords) = grid.try_move(&walker.position, &walker.direction) {
         let target = grid.get_by_coords(&coords);
 
         match target {
             Tile::Empty => {
-                visited_coords.insert(coords.clone());
+                visits.cells.insert(&coords);
                 walker.position = coords;
             }
             Tile::Wall => {
-                let hit_direction = (coords, walker.direction.clone());
-                if wall_hit_directions.contains(&hit_direction) {
+                if !visits.wall_hits.insert(&coords, &walker.direction) {
                     return Err(LoopDetected);
-                } else {
-                    wall_hit_directions.insert(hit_direction);
                 }
 
-                walker.direction = match walker.direction {
-                    GridDirection::East => GridDirection::South,
-                    GridDirection::West => GridDirection::North,
-                    GridDirection::South => GridDirection::West,
-                    GridDirection::North => GridDirection::East,
-                    _ => panic!("Unsupported grid direction"),
-                }
+                walker.direction = walker.direction.turn_right();
             }
         }
     }
 
-    Ok(visited_coords)
+    Ok(())
 }
 
-fn parse_input(input: &str) -> (Grid<Tile>, Walker) {
+fn parse_input(input: &str) -> Result<(Grid<Tile>, Walker), ParseError> {
     let mut walker: Option<Walker> = None;
 
-    let lines: Vec<Vec<Tile>> = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| match c {
-                    '.' => Tile::Empty,
-                    '#' => Tile::Wall,
-                    '^' => {
-                        walker = Some(Walker {
-                            position: Coords {
-                                x: x as i32,
-                                y: y as i32,
-                            },
-                            direction: GridDirection::North,
-                        });
-                        Tile::Empty
-                    }
-                    _ => panic!("Unsupported input char"),
-                })
-                .collect()
-        })
-        .collect();
-
-    let grid = Grid::from_lines(lines);
-
-    (grid, walker.expect("Initial walker positon not found"))
+    let grid = grid_with(input, |x, y, c| match c {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::Wall),
+        '^' => {
+            walker = Some(Walker {
+                position: Coords {
+                    x: x as i32,
+                    y: y as i32,
+                },
+                direction: GridDirection::North,
+            });
+            Ok(Tile::Empty)
+        }
+        _ => Err(format!("unsupported tile `{c}`")),
+    })?;
+
+    let walker =
+        walker.ok_or_else(|| ParseError::at_end(input, "initial walker position not found"))?;
+
+    Ok((grid, walker))
 }
 
 impl fmt::Display for Tile {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let c = match self {
             Tile::Empty => '.',
-            Tile::Wall { .. } => '#',
+            Tile::Wall => '#',
         };
         write!(f, "{c}")?;
         Ok(())
@@ -138,11 +204,46 @@ mod tests {
 
     #[test]
     fn test_first() {
-        assert_eq!(solve_first(&read_input_file("day06/test1.txt")), 41);
+        assert_eq!(
+            solve_first(&parse_input(&read_input_file("day06/test1.txt")).unwrap()),
+            41
+        );
+    }
+
+    #[test]
+    fn parse_input_reports_unsupported_tile() {
+        assert_eq!(
+            parse_input("..#\n.^X\n").err(),
+            Some(ParseError::at(1, 2, "unsupported tile `X`"))
+        );
+        assert_eq!(
+            parse_input("..#\n...\n").err(),
+            Some(ParseError::at(1, 3, "initial walker position not found"))
+        );
     }
 
     #[test]
     fn test_second() {
-        assert_eq!(solve_second(&read_input_file("day06/test1.txt")), 6);
+        assert_eq!(
+            solve_second(&parse_input(&read_input_file("day06/test1.txt")).unwrap()),
+            6
+        );
+    }
+
+    #[test]
+    fn render_marks_path_and_obstructions() {
+        let input = parse_input(&read_input_file("day06/test1.txt")).unwrap();
+        let text = draw(&input).picture().to_text();
+
+        assert_eq!(text.matches('O').count(), 6);
+        assert_eq!(text.matches('S').count(), 1);
+        assert_eq!(text.lines().nth(6), Some(".#XOSXXXX."));
+    }
+
+    #[test]
+    fn render_looping_map() {
+        let input = parse_input(".#..\n...#\n#^..\n..#.\n").unwrap();
+
+        assert_eq!(draw(&input).picture().to_text(), ".#..\n.XX#\n#SX.\n..#.\n");
     }
 }
@@ -1,4 +1,30 @@
-struct Problem {
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::{key_values, parse_token, whitespace_list, ParseError},
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Problem>;
+
+    const DAY: u32 = 7;
+    const NAME: &'static str = "Bridge Repair";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub struct Problem {
     target: u64,
     nums: Vec<u64>,
 }
@@ -62,19 +88,19 @@ enum ComputationState<'a> {
     Incomplete(SubProblem<'a>),
 }
 
-pub fn solve_first(input: &str) -> usize {
-    solve(input, &[Operation::Multiply, Operation::Add])
+pub fn solve_first(problems: &[Problem]) -> usize {
+    solve(problems, &[Operation::Multiply, Operation::Add])
 }
 
-pub fn solve_second(input: &str) -> usize {
-    solve(input, &[Operation::Concat, Operation::Multiply, Operation::Add])
+pub fn solve_second(problems: &[Problem]) -> usize {
+    solve(problems, &[Operation::Concat, Operation::Multiply, Operation::Add])
 }
 
-fn solve(input: &str, operations: &[Operation]) -> usize {
-    parse_input(input)
+fn solve(problems: &[Problem], operations: &[Operation]) -> usize {
+    problems
         .iter()
         .map(SubProblem::from_problem)
-        .filter(|sub_problem| is_feasible(sub_problem, &operations))
+        .filter(|sub_problem| is_feasible(sub_problem, operations))
         .map(|problem| problem.target)
         .sum::<u64>() as usize
 }
@@ -89,16 +115,14 @@ fn is_feasible(sub_problem: &SubProblem, operations: &[Operation]) -> bool {
         })
 }
 
-fn parse_input(input: &str) -> Vec<Problem> {
-    input
-        .lines()
-        .map(|line| line.split_once(':').unwrap())
-        .map(|(first, second)| Problem {
-            target: first.parse::<u64>().unwrap(),
-            nums: second
-                .split_whitespace()
-                .map(|n| n.parse::<u64>().unwrap())
-                .collect(),
+fn parse_input(input: &str) -> Result<Vec<Problem>, ParseError> {
+    key_values(input, input)?
+        .into_iter()
+        .map(|(target, nums)| {
+            Ok(Problem {
+                target: parse_token(input, target)?,
+                nums: whitespace_list(input, nums)?,
+            })
         })
         .collect()
 }
@@ -111,16 +135,33 @@ mod tests {
 
     #[test]
     fn test_first() {
-        assert_eq!(solve_first(&read_input_file("day07/test1.txt")), 3749);
+        assert_eq!(
+            solve_first(&parse_input(&read_input_file("day07/test1.txt")).unwrap()),
+            3749
+        );
     }
 
     #[test]
     fn test_first_b() {
-        assert_eq!(solve_first(&read_input_file("day07/test2.txt")), 9);
+        assert_eq!(
+            solve_first(&parse_input(&read_input_file("day07/test2.txt")).unwrap()),
+            9
+        );
     }
 
     #[test]
     fn test_second() {
-        assert_eq!(solve_second(&read_input_file("day07/test1.txt")), 11387);
+        assert_eq!(
+            solve_second(&parse_input(&read_input_file("day07/test1.txt")).unwrap()),
+            11387
+        );
+    }
+
+    #[test]
+    fn parse_input_rejects_invalid_operand() {
+        assert_eq!(
+            parse_input("190: 10 x 19\n").err(),
+            Some(ParseError::at(0, 8, "invalid value `x`"))
+        );
     }
 }
@@ -1,5 +1,7 @@
 use std::rc::Rc;
 
+use crate::utils::parse::{key_values, parse_token, ParseError};
+
 use super::operations::apply;
 
 pub type Int = u64;
@@ -54,13 +56,13 @@ pub enum ComboOperand {
 }
 
 impl ComboOperand {
-    fn parse(operand: u8) -> Self {
+    fn parse(operand: u8) -> Result<Self, String> {
         match operand {
-            0..=3 => ComboOperand::Literal(operand.into()),
-            4 => ComboOperand::Register(Register::A),
-            5 => ComboOperand::Register(Register::B),
-            6 => ComboOperand::Register(Register::C),
-            _ => panic!("Unsupported operand"),
+            0..=3 => Ok(ComboOperand::Literal(operand.into())),
+            4 => Ok(ComboOperand::Register(Register::A)),
+            5 => Ok(ComboOperand::Register(Register::B)),
+            6 => Ok(ComboOperand::Register(Register::C)),
+            _ => Err(format!("unsupported combo operand {operand}")),
         }
     }
 }
@@ -93,40 +95,67 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn parse(opcode: u8, operand: u8) -> Self {
+    pub fn parse(opcode: u8, operand: u8) -> Result<Self, String> {
         match opcode {
-            0 => Instruction::DivideToA(ComboOperand::parse(operand)),
-            1 => Instruction::XorBWithLiteral(operand.into()),
-            2 => Instruction::Modulo8ToB(ComboOperand::parse(operand)),
-            3 => Instruction::JumpIfANotZero(operand.into()),
-            4 => Instruction::XorBWithC,
-            5 => Instruction::Modulo8ToOut(ComboOperand::parse(operand)),
-            6 => Instruction::DivideToB(ComboOperand::parse(operand)),
-            7 => Instruction::DivideToC(ComboOperand::parse(operand)),
-            _ => panic!("Unsupported opcode"),
+            0 => Ok(Instruction::DivideToA(ComboOperand::parse(operand)?)),
+            1 => Ok(Instruction::XorBWithLiteral(operand.into())),
+            2 => Ok(Instruction::Modulo8ToB(ComboOperand::parse(operand)?)),
+            3 => Ok(Instruction::JumpIfANotZero(operand.into())),
+            4 => Ok(Instruction::XorBWithC),
+            5 => Ok(Instruction::Modulo8ToOut(ComboOperand::parse(operand)?)),
+            6 => Ok(Instruction::DivideToB(ComboOperand::parse(operand)?)),
+            7 => Ok(Instruction::DivideToC(ComboOperand::parse(operand)?)),
+            _ => Err(format!("unsupported opcode {opcode}")),
         }
     }
 }
 
-pub fn parse_input(input: &str) -> (Computer, Vec<Int>) {
-    let values: Vec<&str> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.split_once(": ").unwrap())
-        .map(|(_, second)| second)
+pub fn parse_input(input: &str) -> Result<(Computer, Vec<Int>), ParseError> {
+    let values: Vec<&str> = key_values(input, input)?
+        .into_iter()
+        .map(|(_, value)| value)
         .collect();
 
-    let register_a: Int = values[0].parse().unwrap();
-    let register_b: Int = values[1].parse().unwrap();
-    let register_c: Int = values[2].parse().unwrap();
-    let codes = values[3]
-        .split(',')
-        .map(|n| n.parse::<u8>().unwrap())
-        .collect::<Vec<u8>>();
+    let [a, b, c, program] = values[..] else {
+        return Err(ParseError::at(
+            0,
+            0,
+            "expected three registers followed by a program",
+        ));
+    };
+
+    let register_a: Int = parse_token(input, a)?;
+    let register_b: Int = parse_token(input, b)?;
+    let register_c: Int = parse_token(input, c)?;
+    let tokens: Vec<&str> = program.split(',').map(str::trim).collect();
+    let codes: Vec<u8> = tokens
+        .iter()
+        .map(|token| parse_token(input, token))
+        .collect::<Result<_, _>>()?;
+
+    if !codes.len().is_multiple_of(2) {
+        return Err(ParseError::at_fragment(
+            input,
+            program,
+            "expected pairs of opcodes and operands",
+        ));
+    }
+
     let instructions = codes
         .chunks(2)
-        .map(|pair| Instruction::parse(pair[0], pair[1]))
-        .collect::<Vec<Instruction>>();
+        .zip(tokens.chunks(2))
+        .map(|(pair, pair_tokens)| {
+            Instruction::parse(pair[0], pair[1]).map_err(|message| {
+                // Every opcode up to 7 exists, so any other failure is about the operand.
+                let token = if pair[0] > 7 {
+                    pair_tokens[0]
+                } else {
+                    pair_tokens[1]
+                };
+                ParseError::at_fragment(input, token, message)
+            })
+        })
+        .collect::<Result<Vec<Instruction>, _>>()?;
 
     let computer = Computer {
         instructions: Rc::from(instructions),
@@ -143,20 +172,26 @@ pub fn parse_input(input: &str) -> (Computer, Vec<Int>) {
 
     let codes = codes.iter().map(|c| *c as Int).collect();
 
-    (computer, codes)
+    Ok((computer, codes))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::utils::input::normalize;
+
     use super::*;
 
     #[test]
     fn parse_test_input_is_correct() {
-        let input = "Register A: 729
+        let input = normalize(
+            "
+            Register A: 729
             Register B: 0
             Register C: 0
 
-            Program: 0,1,5,4,3,0";
+            Program: 0,1,5,4,3,0
+            ",
+        );
 
         let expected = Computer {
             state: State {
@@ -175,18 +210,22 @@ mod tests {
             ]),
         };
 
-        let (result, _) = parse_input(input);
+        let (result, _) = parse_input(&input).unwrap();
 
         assert_eq!(result, expected);
     }
 
     #[test]
     fn parse_problem_input_is_correct() {
-        let input = "Register A: 28066687
+        let input = normalize(
+            "
+            Register A: 28066687
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0";
+            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0
+            ",
+        );
 
         let expected = Computer {
             state: State {
@@ -210,8 +249,30 @@ mod tests {
             ]),
         };
 
-        let (result, _) = parse_input(input);
+        let (result, _) = parse_input(&input).unwrap();
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn parse_invalid_operand_reports_position() {
+        let input = normalize(
+            "
+            Register A: 729
+            Register B: 0
+            Register C: 0
+
+            Program: 0,7,5,4
+            ",
+        );
+
+        assert_eq!(
+            parse_input(&input).err(),
+            Some(ParseError::at(4, 11, "unsupported combo operand 7"))
+        );
+        assert_eq!(
+            parse_input(&input.replace("0,7", "9,7")).err(),
+            Some(ParseError::at(4, 9, "unsupported opcode 9"))
+        );
+    }
 }
@@ -1,17 +1,40 @@
 use data::{parse_input, ComputationHalted, Computer, Int, RegisterState, State};
 
+use crate::{
+    solution::{Answer, Solution},
+    utils::parse::ParseError,
+};
+
 mod data;
 mod operations;
 
-pub fn solve_first(input: &str) -> String {
-    let (computer, _) = parse_input(input);
-    let final_state = run_until_halt(computer);
-    serialize_output(&final_state)
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Computer, Vec<Int>);
+
+    const DAY: u32 = 17;
+    const NAME: &'static str = "Chronospatial Computer";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
 }
 
-pub fn solve_second(input: &str) -> Int {
-    let (original_computer, complete_output) = parse_input(input);
+pub fn solve_first((computer, _): &(Computer, Vec<Int>)) -> String {
+    let final_state = run_until_halt(computer.clone());
+    serialize_output(&final_state)
+}
 
+pub fn solve_second((original_computer, complete_output): &(Computer, Vec<Int>)) -> Int {
     let mut candidates: Vec<Int> = vec![0];
 
     for inst_count in 1..=complete_output.len() {
@@ -63,12 +86,7 @@ fn run_until_halt(mut computer: Computer) -> State {
 fn run_matches_output(mut computer: Computer, target_output: &Vec<Int>) -> bool {
     let mut output_len = 0;
 
-    loop {
-        match computer.run_step() {
-            Ok(_) => {}
-            Err(ComputationHalted(_)) => break,
-        }
-
+    while computer.run_step().is_ok() {
         if computer.state.output.len() > output_len {
             if computer.state.output[output_len] != target_output[output_len] {
                 break;
@@ -96,43 +114,51 @@ fn serialize_output(state: &State) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input_file;
+    use crate::utils::{input::normalize, read_input_file};
 
     use super::*;
 
     #[test]
     fn test_first_example() {
         assert_eq!(
-            solve_first(&read_input_file("day17/test1.txt")),
+            solve_first(&parse_input(&read_input_file("day17/test1.txt")).unwrap()),
             "4,6,3,5,6,3,5,2,1,0"
         );
     }
 
     #[test]
     fn test_first_example_2() {
-        let input = "Register A: 10
+        let input = normalize(
+            "
+            Register A: 10
             Register B: 0
             Register C: 0
 
-            Program: 5,0,5,1,5,4";
+            Program: 5,0,5,1,5,4
+            ",
+        );
 
         let expected_output = "0,1,2";
-        let result = solve_first(input);
+        let result = solve_first(&parse_input(&input).unwrap());
 
         assert_eq!(result, expected_output);
     }
 
     #[test]
     fn test_first_example_3() {
-        let input = "Register A: 2024
+        let input = normalize(
+            "
+            Register A: 2024
             Register B: 0
             Register C: 0
 
-            Program: 0,1,5,4,3,0";
+            Program: 0,1,5,4,3,0
+            ",
+        );
 
         let expected_output = "4,2,5,6,7,7,7,7,3,1,0";
 
-        let (computer, _) = parse_input(input);
+        let (computer, _) = parse_input(&input).unwrap();
         let final_state = run_until_halt(computer);
         let result = serialize_output(&final_state);
 
@@ -142,84 +168,111 @@ mod tests {
 
     #[test]
     fn test_first_example_4() {
-        let input = "Register A: 117440
+        let input = normalize(
+            "
+            Register A: 117440
             Register B: 0
             Register C: 0
 
-            Program: 0,3,5,4,3,0";
+            Program: 0,3,5,4,3,0
+            ",
+        );
 
         let expected_output = "0,3,5,4,3,0";
-        let result = solve_first(input);
+        let result = solve_first(&parse_input(&input).unwrap());
 
         assert_eq!(result, expected_output);
     }
 
     #[test]
     fn test_first_solution() {
-        let input = "Register A: 28066687
+        let input = normalize(
+            "
+            Register A: 28066687
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0";
+            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0
+            ",
+        );
 
         let expected_output = "7,3,0,5,7,1,4,0,5";
-        let result = solve_first(input);
+        let result = solve_first(&parse_input(&input).unwrap());
 
         assert_eq!(result, expected_output);
     }
 
     #[test]
     fn test_cooking() {
-        let input = "Register A: 46
+        let input = normalize(
+            "
+            Register A: 46
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0";
+            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0
+            ",
+        );
 
         let expected_output = "3,0";
-        let result = solve_first(input);
+        let result = solve_first(&parse_input(&input).unwrap());
 
         assert_eq!(result, expected_output);
     }
 
     #[test]
     fn test_cooking_2() {
-        let input = "Register A: 368
+        let input = normalize(
+            "
+            Register A: 368
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0";
+            Program: 2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0
+            ",
+        );
 
         let expected_output = "5,3,0";
-        let result = solve_first(input);
+        let result = solve_first(&parse_input(&input).unwrap());
 
         assert_eq!(result, expected_output);
     }
 
     #[test]
     fn test_second_example() {
-        assert_eq!(solve_second(&read_input_file("day17/test2.txt")), 117440);
+        assert_eq!(
+            solve_second(&parse_input(&read_input_file("day17/test2.txt")).unwrap()),
+            117440
+        );
     }
 
     #[test]
     fn radek_input() {
-        let input = "Register A: 25986278
+        let input = normalize(
+            "
+            Register A: 25986278
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,4,7,5,4,1,1,4,5,5,0,3,3,0";
+            Program: 2,4,1,4,7,5,4,1,1,4,5,5,0,3,3,0
+            ",
+        );
 
-        assert_eq!(solve_second(input), 156985331222018);
+        assert_eq!(solve_second(&parse_input(&input).unwrap()), 156985331222018);
     }
 
     #[test]
     fn tomik_input() {
-        let input = "Register A: 66245665
+        let input = normalize(
+            "
+            Register A: 66245665
             Register B: 0
             Register C: 0
 
-            Program: 2,4,1,7,7,5,1,7,4,6,0,3,5,5,3,0";
+            Program: 2,4,1,7,7,5,1,7,4,6,0,3,5,5,3,0
+            ",
+        );
 
-        assert_eq!(solve_second(input), 265061364597659);
+        assert_eq!(solve_second(&parse_input(&input).unwrap()), 265061364597659);
     }
 }
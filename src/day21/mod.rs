@@ -3,16 +3,40 @@
 
 use std::fmt::Debug;
 
-use regex::Regex;
+use crate::{
+    solution::{Answer, Solution},
+    utils::{
+        grid::Coords,
+        parse::{parse_token, ParseError},
+    },
+};
 
-use crate::utils::grid::Coords;
+pub struct Day21;
 
-pub fn solve_first(input: &str) -> usize {
-    let codes = parse_input(input);
+impl Solution for Day21 {
+    type Input = Vec<(Vec<char>, usize)>;
+
+    const DAY: u32 = 21;
+    const NAME: &'static str = "Keypad Conundrum";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_first(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_second(input).into()
+    }
+}
+
+pub fn solve_first(codes: &[(Vec<char>, usize)]) -> usize {
     let result = codes
         .iter()
         .map(|(chars, num_value)| {
-            let cost = compute_cost(&chars);
+            let cost = compute_cost(chars);
             cost * num_value
         })
         .sum();
@@ -20,7 +44,7 @@ pub fn solve_first(input: &str) -> usize {
     result
 }
 
-fn compute_cost(chars: &Vec<char>) -> usize {
+fn compute_cost(chars: &[char]) -> usize {
     42
     // let mut current_char = 'A';
     // let mut numpad_moves: Vec<Move> = Vec::new();
@@ -236,37 +260,49 @@ fn get_arrowpad_moves(src: Arrow, dst: Arrow) -> Vec<Move> {
     moves
 }
 
-pub fn solve_second(_input: &str) -> usize {
+pub fn solve_second(_codes: &[(Vec<char>, usize)]) -> usize {
     420
 }
 
-fn parse_input(input: &str) -> Vec<(Vec<char>, usize)> {
-    let num_regex = Regex::new(r"^\d+").unwrap();
+fn parse_input(input: &str) -> Result<Vec<(Vec<char>, usize)>, ParseError> {
     input
         .lines()
-        .map(|line| line.trim())
         .map(|line| {
             let chars = line.chars().collect();
-            let num_value = num_regex.find(line).unwrap().as_str().parse().unwrap();
-            (chars, num_value)
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                return Err(ParseError::at_fragment(
+                    input,
+                    line,
+                    "expected a numeric code",
+                ));
+            }
+            let num_value = parse_token(input, &line[..digits])?;
+            Ok((chars, num_value))
         })
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::utils::input::normalize;
+
     use super::*;
 
     #[test]
     fn parse_input_works() {
-        let input = "029A
-        980A
-        179A
-        456A
-        379A";
+        let input = normalize(
+            "
+            029A
+            980A
+            179A
+            456A
+            379A
+            ",
+        );
 
         assert_eq!(
-            parse_input(input),
+            parse_input(&input).unwrap(),
             vec![
                 (vec!['0', '2', '9', 'A'], 29),
                 (vec!['9', '8', '0', 'A'], 980),
@@ -275,6 +311,10 @@ mod tests {
                 (vec!['3', '7', '9', 'A'], 379),
             ]
         );
+        assert_eq!(
+            parse_input("029A\nA029\n").err(),
+            Some(ParseError::at(1, 0, "expected a numeric code"))
+        );
     }
 
     #[test]
@@ -339,35 +379,35 @@ mod tests {
     // fn test_first_example_029a() {
     //     let input = "029A";
 
-    //     assert_eq!(solve_first(input), 68 * 29);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 68 * 29);
     // }
 
     // #[test]
     // fn test_first_example_980a() {
     //     let input = "980A";
 
-    //     assert_eq!(solve_first(input), 60 * 980);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 60 * 980);
     // }
 
     // #[test]
     // fn test_first_example_179a() {
     //     let input = "179A";
 
-    //     assert_eq!(solve_first(input), 68 * 179);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 68 * 179);
     // }
 
     // #[test]
     // fn test_first_example_456a() {
     //     let input = "456A";
 
-    //     assert_eq!(solve_first(input), 64 * 456);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 64 * 456);
     // }
 
     // #[test]
     // fn test_first_example_379a() {
     //     let input = "379A";
 
-    //     assert_eq!(solve_first(input), 64 * 379);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 64 * 379);
     // }
 
     // #[test]
@@ -378,12 +418,12 @@ mod tests {
     //         456A
     //         379A";
 
-    //     assert_eq!(solve_first(input), 126384);
+    //     assert_eq!(solve_first(&parse_input(input).unwrap()), 126384);
     // }
 
     // #[test]
     // fn test_second_example() {
     //     let input = "";
-    //     assert_eq!(solve_second(input), 420);
+    //     assert_eq!(solve_second(&parse_input(input).unwrap()), 420);
     // }
 }
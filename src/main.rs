@@ -1,113 +1,280 @@
-use utils::read_input_file;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day17;
-mod day21;
-mod utils;
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let day: i32 = args[1].parse().expect("Invalid day number");
+use std::{fs, io, path::PathBuf, process::ExitCode};
 
-    match day {
-        1 => {
-            println!(
-                "{}",
-                day01::solve_first(&read_input_file("day01/input.txt"))
-            );
-            println!(
-                "{}",
-                day01::solve_second(&read_input_file("day01/input.txt"))
-            );
-        }
-        2 => {
-            println!(
-                "{}",
-                day02::solve_first(&read_input_file("day02/input.txt"))
-            );
-            println!(
-                "{}",
-                day02::solve_second(&read_input_file("day02/input.txt"))
-            );
-        }
-        3 => {
-            println!(
-                "{}",
-                day03::solve_first(&read_input_file("day03/input.txt"))
-            );
-            println!(
-                "{}",
-                day03::solve_second(&read_input_file("day03/input.txt"))
-            );
-        }
-        4 => {
-            println!(
-                "{}",
-                day04::solve_first(&read_input_file("day04/input.txt"))
-            );
-            println!(
-                "{}",
-                day04::solve_second(&read_input_file("day04/input.txt"))
-            );
+use aoc2024::bench::{bench, parse_csv, render, to_csv};
+use aoc2024::cli::{parse_args, Command, InputSource, USAGE};
+use aoc2024::runner::Summary;
+use aoc2024::scaffold::scaffold_day;
+use aoc2024::solution::{Parts, Puzzle, Run};
+use aoc2024::utils::input::{normalize, InputResolver};
+use aoc2024::utils::parse::ParseError;
+use aoc2024::verify::{Answers, Verification, ANSWERS_FILE};
+use aoc2024::REGISTRY;
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
         }
-        5 => {
-            println!(
-                "{}",
-                day05::solve_first(&read_input_file("day05/input.txt"))
-            );
-            println!(
-                "{}",
-                day05::solve_second(&read_input_file("day05/input.txt"))
-            );
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
         }
-        6 => {
-            println!(
-                "{}",
-                day06::solve_first(&read_input_file("day06/input.txt"))
-            );
-            println!(
-                "{}",
-                day06::solve_second(&read_input_file("day06/input.txt"))
-            );
+        Command::Run {
+            day,
+            parts,
+            input,
+            inputs_dir,
+        } => run(day, parts, &input, &InputResolver::new(inputs_dir)),
+        Command::Verify {
+            day,
+            inputs_dir,
+            answers,
+        } => verify(day, answers, &InputResolver::new(inputs_dir)),
+        Command::Bench {
+            day,
+            parts,
+            runs,
+            warmup,
+            inputs_dir,
+            output,
+            compare,
+        } => run_bench(
+            day,
+            parts,
+            warmup,
+            runs,
+            &InputResolver::new(inputs_dir),
+            output,
+            compare,
+        ),
+        Command::Render {
+            day,
+            input,
+            inputs_dir,
+            image,
+            scale,
+            plain,
+        } => render_day(
+            day,
+            &input,
+            &InputResolver::new(inputs_dir),
+            image,
+            scale,
+            plain,
+        ),
+        Command::New { day, src_dir } => new_day(day, src_dir),
+    };
+
+    result.unwrap_or_else(|message| {
+        eprintln!("error: {message}");
+        ExitCode::FAILURE
+    })
+}
+
+fn run(
+    day: Option<u32>,
+    parts: Parts,
+    input: &InputSource,
+    inputs: &InputResolver,
+) -> Result<ExitCode, String> {
+    let mut summary = Summary::new();
+
+    for puzzle in select_puzzles(day)? {
+        let run = read_input(puzzle.day(), input, inputs)
+            .and_then(|input| run_puzzle(puzzle, &input, parts));
+
+        match run {
+            Ok(run) => summary.add(puzzle, run),
+            Err(error) => {
+                eprintln!("error: {error}");
+                summary.add_error(puzzle.day(), error);
+            }
         }
-        7 => {
-            println!(
-                "{}",
-                day07::solve_first(&read_input_file("day07/input.txt"))
-            );
-            println!(
-                "{}",
-                day07::solve_second(&read_input_file("day07/input.txt"))
-            );
+    }
+
+    print!("{}", summary.render());
+
+    if summary.is_success() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn verify(
+    day: Option<u32>,
+    answers_path: Option<PathBuf>,
+    inputs: &InputResolver,
+) -> Result<ExitCode, String> {
+    let answers_text = match answers_path {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display()))?,
+        None => read_bundled(inputs, ANSWERS_FILE)?,
+    };
+    let answers =
+        Answers::parse(&answers_text).map_err(|error| format!("Invalid answers: {error}"))?;
+
+    let mut verification = Verification::new();
+
+    for puzzle in select_puzzles(day)? {
+        let run = read_input(puzzle.day(), &InputSource::Default, inputs)
+            .and_then(|input| run_puzzle(puzzle, &input, Parts::Both));
+
+        match run {
+            Ok(run) => verification.add(puzzle, &run, &answers),
+            Err(error) => {
+                eprintln!("error: {error}");
+                verification.add_error(puzzle.day(), error);
+            }
         }
-        17 => {
-            println!(
-                "{}",
-                day17::solve_first(&read_input_file("day17/input.txt"))
-            );
-            println!(
-                "{}",
-                day17::solve_second(&read_input_file("day17/input.txt"))
-            );
+    }
+
+    print!("{}", verification.render());
+
+    if verification.is_success() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn run_bench(
+    day: Option<u32>,
+    parts: Parts,
+    warmup: usize,
+    runs: usize,
+    inputs: &InputResolver,
+    output: Option<PathBuf>,
+    compare: Option<PathBuf>,
+) -> Result<ExitCode, String> {
+    let baseline = match compare {
+        Some(path) => {
+            let text = fs::read_to_string(&path)
+                .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+            Some(parse_csv(&text).map_err(|error| format!("Invalid {}: {error}", path.display()))?)
         }
-        21 => {
-            println!(
-                "{}",
-                day21::solve_first(&read_input_file("day21/input.txt"))
-            );
-            println!(
-                "{}",
-                day21::solve_second(&read_input_file("day21/input.txt"))
-            );
+        None => None,
+    };
+
+    let mut results = Vec::new();
+    let mut skipped = 0;
+
+    for puzzle in select_puzzles(day)? {
+        let day_results =
+            read_input(puzzle.day(), &InputSource::Default, inputs).and_then(|input| {
+                bench(puzzle, &input, parts, warmup, runs)
+                    .map_err(|error| parse_failure(puzzle, &input, &error))
+            });
+
+        match day_results {
+            Ok(day_results) => results.extend(day_results),
+            Err(error) => {
+                eprintln!("warning: skipping day {}: {error}", puzzle.day());
+                skipped += 1;
+            }
         }
-        _ => {
-            println!("Day not implemented");
+    }
+
+    print!("{}", render(&results, baseline.as_deref()));
+
+    if let Some(path) = output {
+        fs::write(&path, to_csv(&results))
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))?;
+    }
+
+    if skipped == 0 {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn render_day(
+    day: u32,
+    input: &InputSource,
+    inputs: &InputResolver,
+    image: Option<PathBuf>,
+    scale: usize,
+    plain: bool,
+) -> Result<ExitCode, String> {
+    let puzzle = REGISTRY
+        .get(day)
+        .ok_or_else(|| format!("Day {day} is not implemented"))?;
+    let input = read_input(day, input, inputs)?;
+    let picture = puzzle
+        .render(&input)
+        .map_err(|error| parse_failure(puzzle, &input, &error))?
+        .ok_or_else(|| format!("Day {day} has no drawing"))?;
+
+    match image {
+        Some(path) => {
+            picture
+                .write_image(&path, scale)
+                .map_err(|error| format!("Cannot write {}: {error}", path.display()))?;
+            println!("Wrote {}", path.display());
         }
+        None if plain => print!("{}", picture.to_text()),
+        None => print!("{}", picture.to_ansi()),
     }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_day(day: u32, src_dir: Option<PathBuf>) -> Result<ExitCode, String> {
+    let src_dir = src_dir.unwrap_or_else(|| PathBuf::from("src"));
+
+    for path in scaffold_day(&src_dir, day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_puzzle(puzzle: &dyn Puzzle, input: &str, parts: Parts) -> Result<Run, String> {
+    puzzle
+        .run(input, parts)
+        .map_err(|error| parse_failure(puzzle, input, &error))
+}
+
+fn parse_failure(puzzle: &dyn Puzzle, input: &str, error: &ParseError) -> String {
+    format!(
+        "Cannot parse input of day {}: {}",
+        puzzle.day(),
+        error.render(input)
+    )
+}
+
+fn select_puzzles(day: Option<u32>) -> Result<Vec<&'static dyn Puzzle>, String> {
+    match day {
+        Some(day) => Ok(vec![REGISTRY
+            .get(day)
+            .ok_or_else(|| format!("Day {day} is not implemented"))?]),
+        None => Ok(REGISTRY.iter().collect()),
+    }
+}
+
+fn read_input(day: u32, source: &InputSource, inputs: &InputResolver) -> Result<String, String> {
+    let input = match source {
+        InputSource::Default => read_bundled(inputs, &format!("day{day:02}/input.txt")),
+        InputSource::Example(number) => {
+            read_bundled(inputs, &format!("day{day:02}/test{number}.txt"))
+        }
+        InputSource::File(path) => fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {error}", path.display())),
+        InputSource::Stdin => io::read_to_string(io::stdin())
+            .map_err(|error| format!("Cannot read standard input: {error}")),
+    }?;
+
+    Ok(normalize(&input))
+}
+
+fn read_bundled(inputs: &InputResolver, relative_path: &str) -> Result<String, String> {
+    inputs
+        .read(relative_path)
+        .map_err(|error| format!("Cannot read {relative_path}: {error}"))
 }
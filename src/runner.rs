@@ -0,0 +1,205 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::solution::{Puzzle, Run};
+
+/// Results of running a set of days, printable as a summary table.
+pub struct Summary {
+    /// Run of every day, or the reason it could not be run.
+    runs: Vec<(u32, Result<Run, String>)>,
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Summary { runs: Vec::new() }
+    }
+
+    pub fn add(&mut self, puzzle: &'static dyn Puzzle, run: Run) {
+        self.runs.push((puzzle.day(), Ok(run)));
+    }
+
+    /// Records that `day` could not be run, so that the other days can still be shown.
+    pub fn add_error(&mut self, day: u32, message: impl Into<String>) {
+        self.runs.push((day, Err(message.into())));
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.runs.iter().all(|(_, run)| run.is_ok())
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.runs
+            .iter()
+            .filter_map(|(_, run)| run.as_ref().ok())
+            .map(Run::total_duration)
+            .sum()
+    }
+
+    /// Renders one row for parsing and one row per part of every day, followed by the total.
+    /// Days that could not be run get a single row with the first line of their error.
+    pub fn render(&self) -> String {
+        let rows: Vec<[String; 4]> = self
+            .runs
+            .iter()
+            .flat_map(|(day, run)| {
+                let day = day.to_string();
+                let run = match run {
+                    Ok(run) => run,
+                    Err(message) => {
+                        let message = message.lines().next().unwrap_or_default();
+                        let error_row =
+                            [day, "error".to_string(), message.to_string(), String::new()];
+                        return vec![error_row];
+                    }
+                };
+                let parse_row = [
+                    day.clone(),
+                    "parse".to_string(),
+                    String::new(),
+                    format_duration(run.parse_duration),
+                ];
+                let part_rows = [("1", &run.part_one), ("2", &run.part_two)]
+                    .into_iter()
+                    .filter_map(|(part, part_run)| {
+                        part_run.as_ref().map(|part_run| {
+                            [
+                                day.clone(),
+                                part.to_string(),
+                                part_run.answer.to_string(),
+                                format_duration(part_run.duration),
+                            ]
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                std::iter::once(parse_row).chain(part_rows).collect()
+            })
+            .collect();
+
+        let mut table = Table::new([Align::Right, Align::Right, Align::Left, Align::Right]);
+        table.row(["Day", "Part", "Answer", "Time"].map(String::from));
+        table.separator();
+        for row in rows {
+            table.row(row);
+        }
+        table.separator();
+        table.row([
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            format_duration(self.total_duration()),
+        ]);
+
+        table.render()
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+enum Line<const N: usize> {
+    Row([String; N]),
+    Separator,
+}
+
+/// Plain-text table with `N` columns sized to fit their widest cell.
+pub struct Table<const N: usize> {
+    align: [Align; N],
+    lines: Vec<Line<N>>,
+}
+
+impl<const N: usize> Table<N> {
+    pub fn new(align: [Align; N]) -> Self {
+        Table {
+            align,
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, cells: [String; N]) {
+        self.lines.push(Line::Row(cells));
+    }
+
+    pub fn separator(&mut self) {
+        self.lines.push(Line::Separator);
+    }
+
+    pub fn render(&self) -> String {
+        let mut widths = [0; N];
+        for line in &self.lines {
+            if let Line::Row(cells) = line {
+                for (width, cell) in widths.iter_mut().zip(cells) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+        }
+
+        let mut output = String::new();
+        for line in &self.lines {
+            let cells: Vec<String> = match line {
+                Line::Row(cells) => cells
+                    .iter()
+                    .zip(widths)
+                    .zip(self.align)
+                    .map(|((cell, width), align)| match align {
+                        Align::Left => format!("{cell:<width$}"),
+                        Align::Right => format!("{cell:>width$}"),
+                    })
+                    .collect(),
+                Line::Separator => widths.iter().map(|width| "-".repeat(*width)).collect(),
+            };
+            let joiner = match line {
+                Line::Row(_) => " | ",
+                Line::Separator => "-+-",
+            };
+            writeln!(output, "{}", cells.join(joiner).trim_end()).unwrap();
+        }
+
+        output
+    }
+}
+
+/// Formats a duration with a unit suited to its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1_000.0 {
+        format!("{micros:.1} µs")
+    } else if micros < 1_000_000.0 {
+        format!("{:.2} ms", micros / 1_000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_picks_unit() {
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5 µs");
+        assert_eq!(format_duration(Duration::from_micros(2_345)), "2.35 ms");
+        assert_eq!(format_duration(Duration::from_millis(3_210)), "3.21 s");
+    }
+
+    #[test]
+    fn summary_shows_errors() {
+        let mut summary = Summary::new();
+        summary.add_error(5, "Cannot read day05/input.txt\nsecond line");
+
+        assert!(!summary.is_success());
+        assert_eq!(
+            summary.render().lines().nth(2),
+            Some("    5 | error | Cannot read day05/input.txt |")
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Module template with `Day0N`/`day0N` placeholders, day number 0 and an empty name.
+const TEMPLATE: &str = include_str!("day00/mod.rs");
+
+/// Creates `dayNN/mod.rs` with empty `input.txt` and `test1.txt` in `src_dir`
+/// and registers the new module in `lib.rs`. Returns the created and modified files.
+pub fn scaffold_day(src_dir: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {day}, expected 1 to 25"));
+    }
+
+    let day_dir = src_dir.join(format!("day{day:02}"));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let lib_path = src_dir.join("lib.rs");
+    if !lib_path.is_file() {
+        return Err(format!(
+            "No lib.rs in {}, run from the crate root or pass --src-dir",
+            src_dir.display()
+        ));
+    }
+    let lib_source = fs::read_to_string(&lib_path)
+        .map_err(|error| format!("Cannot read {}: {error}", lib_path.display()))?;
+    let lib_source = register_day(&lib_source, day)?;
+
+    let files = [
+        (day_dir.join("mod.rs"), render_template(day)),
+        (day_dir.join("input.txt"), String::new()),
+        (day_dir.join("test1.txt"), String::new()),
+        (lib_path, lib_source),
+    ];
+
+    fs::create_dir_all(&day_dir)
+        .map_err(|error| format!("Cannot create {}: {error}", day_dir.display()))?;
+
+    for (path, content) in &files {
+        fs::write(path, content)
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn render_template(day: u32) -> String {
+    TEMPLATE
+        .replace("Day0N", &format!("Day{day:02}"))
+        .replace("day0N", &format!("day{day:02}"))
+        .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {day};"))
+        .replace(
+            "const NAME: &'static str = \"\";",
+            &format!("const NAME: &'static str = \"Day {day:02}\";"),
+        )
+}
+
+/// Adds `dayNN::DayNN` to the `register_days!` invocation on its own line, before the first
+/// later day. Other lines of the invocation are kept as they are.
+fn register_day(lib_source: &str, day: u32) -> Result<String, String> {
+    let entry = format!("day{day:02}::Day{day:02},");
+    let start = lib_source
+        .find("register_days!")
+        .ok_or("Cannot find `register_days!` in lib.rs")?;
+    let open = start
+        + lib_source[start..]
+            .find('{')
+            .ok_or("Cannot find the body of `register_days!` in lib.rs")?;
+    let close =
+        matching_brace(lib_source, open).ok_or("Unterminated `register_days!` in lib.rs")?;
+    let body = &lib_source[open + 1..close];
+
+    if body.lines().any(|line| line.trim() == entry) {
+        return Err(format!("Day {day} is already registered"));
+    }
+
+    let mut offset = open + 1;
+    let mut insert_at = None;
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("day") && trimmed.ends_with(',') && trimmed > entry.as_str() {
+            insert_at = Some(offset);
+            break;
+        }
+        offset += line.len();
+    }
+
+    let insert_at = match insert_at {
+        Some(offset) => offset,
+        // After the last line break of the body, so before the line with the closing brace.
+        None => match lib_source[..close].rfind('\n') {
+            Some(newline) if newline > open => newline + 1,
+            _ => return Err("Expected `register_days!` entries on separate lines".to_string()),
+        },
+    };
+
+    Ok(format!(
+        "{}    {entry}\n{}",
+        &lib_source[..insert_at],
+        &lib_source[insert_at..]
+    ))
+}
+
+/// Index of the `}` closing the `{` at `open`.
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_is_filled_in() {
+        let source = render_template(8);
+
+        assert!(source.contains("pub struct Day08;"));
+        assert!(source.contains("impl Solution for Day08"));
+        assert!(source.contains("const DAY: u32 = 8;"));
+        assert!(source.contains("const NAME: &'static str = \"Day 08\";"));
+        assert!(source.contains("\"day08/test1.txt\""));
+        assert!(!source.contains("0N"));
+    }
+
+    #[test]
+    fn day_is_registered_in_order() {
+        let lib = "pub mod utils;\n\nregister_days! {\n    day01::Day01,\n    day17::Day17,\n}\n";
+
+        assert_eq!(
+            register_day(lib, 8).unwrap(),
+            "pub mod utils;\n\nregister_days! {\n    day01::Day01,\n    day08::Day08,\n    day17::Day17,\n}\n"
+        );
+        assert!(register_day(lib, 17).is_err());
+        assert_eq!(
+            register_day(lib, 21).unwrap(),
+            "pub mod utils;\n\nregister_days! {\n    day01::Day01,\n    day17::Day17,\n    day21::Day21,\n}\n"
+        );
+    }
+
+    #[test]
+    fn braces_inside_the_invocation_are_skipped() {
+        let lib = "register_days! {\n    // Parsed with { nested } braces.\n    day01::Day01,\n}\n\nfn main() {}\n";
+
+        assert_eq!(
+            register_day(lib, 2).unwrap(),
+            "register_days! {\n    // Parsed with { nested } braces.\n    day01::Day01,\n    day02::Day02,\n}\n\nfn main() {}\n"
+        );
+        assert!(register_day("pub mod utils;\n", 2).is_err());
+        assert!(register_day("register_days! {\n    day01::Day01,\n", 2).is_err());
+    }
+}
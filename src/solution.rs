@@ -0,0 +1,231 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::utils::{parse::ParseError, render::Picture};
+
+/// Answer to a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Solution of a single day, implemented by every `dayNN` module.
+///
+/// The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    const DAY: u32;
+    const NAME: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// Debug drawing of the input, for days that provide one.
+    fn render(_input: &Self::Input) -> Option<Picture> {
+        None
+    }
+}
+
+/// Which parts of a day should be run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn includes_one(self) -> bool {
+        matches!(self, Parts::One | Parts::Both)
+    }
+
+    pub fn includes_two(self) -> bool {
+        matches!(self, Parts::Two | Parts::Both)
+    }
+}
+
+/// Answer of a single part together with the time it took to compute.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// Result of running the selected parts of a day, with parsing timed separately.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_duration: Duration,
+    pub part_one: Option<PartRun>,
+    pub part_two: Option<PartRun>,
+}
+
+impl Run {
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration
+            + self
+                .part_one
+                .iter()
+                .chain(&self.part_two)
+                .map(|part| part.duration)
+                .sum::<Duration>()
+    }
+}
+
+/// Type-erased view of a [`Solution`] that can be stored in the [`Registry`].
+pub trait Puzzle: Sync {
+    fn day(&self) -> u32;
+    fn name(&self) -> &'static str;
+    fn run(&self, input: &str, parts: Parts) -> Result<Run, ParseError>;
+    fn render(&self, input: &str) -> Result<Option<Picture>, ParseError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn run(&self, input: &str, parts: Parts) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_duration = start.elapsed();
+
+        Ok(Run {
+            parse_duration,
+            part_one: parts
+                .includes_one()
+                .then(|| time_part(|| S::part_one(&parsed))),
+            part_two: parts
+                .includes_two()
+                .then(|| time_part(|| S::part_two(&parsed))),
+        })
+    }
+
+    fn render(&self, input: &str) -> Result<Option<Picture>, ParseError> {
+        Ok(S::render(&S::parse(input)?))
+    }
+}
+
+fn time_part(solve: impl FnOnce() -> Answer) -> PartRun {
+    let start = Instant::now();
+    let answer = solve();
+
+    PartRun {
+        answer,
+        duration: start.elapsed(),
+    }
+}
+
+/// All registered days, ordered by day number.
+pub struct Registry {
+    puzzles: &'static [&'static dyn Puzzle],
+}
+
+impl Registry {
+    pub const fn new(puzzles: &'static [&'static dyn Puzzle]) -> Self {
+        Registry { puzzles }
+    }
+
+    pub fn get(&self, day: u32) -> Option<&'static dyn Puzzle> {
+        self.puzzles.iter().find(|p| p.day() == day).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Puzzle> + '_ {
+        self.puzzles.iter().copied()
+    }
+
+    pub fn days(&self) -> Vec<u32> {
+        self.iter().map(|p| p.day()).collect()
+    }
+}
+
+/// Declares the day modules and collects their solutions into [`REGISTRY`](crate::REGISTRY).
+///
+/// Days must be listed in ascending order.
+#[macro_export]
+macro_rules! register_days {
+    ($($module:ident :: $solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub static REGISTRY: $crate::solution::Registry =
+            $crate::solution::Registry::new(&[$(&$module::$solution),*]);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::REGISTRY;
+
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        let days = REGISTRY.days();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn registry_lookup_by_day() {
+        assert_eq!(REGISTRY.get(4).map(|p| p.name()), Some("Ceres Search"));
+        assert!(REGISTRY.get(25).is_none());
+    }
+}
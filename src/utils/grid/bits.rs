@@ -0,0 +1,338 @@
+use std::fmt;
+
+use super::{check_dimensions, Coords, GridDirection};
+
+/// Fixed-size set of bits packed into words.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Bits {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn get(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Sets or clears the bit, returning its previous value.
+    fn set(&mut self, index: usize, value: bool) -> bool {
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let previous = *word & mask != 0;
+
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+
+        previous
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn union_with(&mut self, other: &Bits) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    fn intersect_with(&mut self, other: &Bits) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+
+    fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+/// Grid of booleans stored as one bit per cell, for visited sets and masks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    pub width: i32,
+    pub height: i32,
+    bits: Bits,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        check_dimensions(width, height);
+
+        BitGrid {
+            width: width as i32,
+            height: height as i32,
+            bits: Bits::new(width * height),
+        }
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        self.bits.get(self.index(x, y))
+    }
+
+    pub fn get_by_coords(&self, coords: &Coords) -> bool {
+        self.get(coords.x, coords.y)
+    }
+
+    /// Sets or clears the cell, returning its previous value.
+    pub fn set(&mut self, x: i32, y: i32, value: bool) -> bool {
+        let index = self.index(x, y);
+        self.bits.set(index, value)
+    }
+
+    pub fn set_by_coords(&mut self, coords: &Coords, value: bool) -> bool {
+        self.set(coords.x, coords.y, value)
+    }
+
+    /// Sets the cell, returning true when it was not set before, like [`HashSet::insert`].
+    ///
+    /// [`HashSet::insert`]: std::collections::HashSet::insert
+    pub fn insert(&mut self, coords: &Coords) -> bool {
+        !self.set_by_coords(coords, true)
+    }
+
+    /// Clears all cells, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// Sets every cell that is set in `other`, which must have the same size.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.check_same_size(other);
+        self.bits.union_with(&other.bits);
+    }
+
+    /// Clears every cell that is not set in `other`, which must have the same size.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.check_same_size(other);
+        self.bits.intersect_with(&other.bits);
+    }
+
+    /// Coordinates of the set cells in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coords> + '_ {
+        self.bits.iter_ones().map(|index| Coords {
+            x: index as i32 % self.width,
+            y: index as i32 / self.width,
+        })
+    }
+
+    fn check_same_size(&self, other: &BitGrid) {
+        if (self.width, self.height) != (other.width, other.height) {
+            panic!(
+                "Combining grid with bounds ({0}, {1}) and grid with bounds ({2}, {3})",
+                self.width, self.height, other.width, other.height
+            )
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> usize {
+        if self.in_bounds(x, y) {
+            (y * self.width + x) as usize
+        } else {
+            panic!(
+                "Accessing grid with bounds ({0}, {1}) at ({x}, {y})",
+                self.width, self.height
+            )
+        }
+    }
+}
+
+/// Renders set cells as `#` and others as `.`.
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Grid with one bit per cardinal direction in every cell, for states like "entered this cell
+/// heading north".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirectionBitGrid {
+    pub width: i32,
+    pub height: i32,
+    bits: Bits,
+}
+
+impl DirectionBitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        check_dimensions(width, height);
+
+        DirectionBitGrid {
+            width: width as i32,
+            height: height as i32,
+            bits: Bits::new(width * height * 4),
+        }
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    pub fn contains(&self, coords: &Coords, direction: &GridDirection) -> bool {
+        self.bits.get(self.index(coords, direction))
+    }
+
+    /// Sets the bit, returning true when it was not set before.
+    pub fn insert(&mut self, coords: &Coords, direction: &GridDirection) -> bool {
+        let index = self.index(coords, direction);
+        !self.bits.set(index, true)
+    }
+
+    pub fn remove(&mut self, coords: &Coords, direction: &GridDirection) -> bool {
+        let index = self.index(coords, direction);
+        self.bits.set(index, false)
+    }
+
+    /// Clears all bits, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Number of set bits over all cells and directions.
+    pub fn count_ones(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// Cells with at least one direction set.
+    pub fn cells(&self) -> BitGrid {
+        let mut cells = BitGrid::new(self.width as usize, self.height as usize);
+
+        for index in self.bits.iter_ones() {
+            cells.bits.set(index / 4, true);
+        }
+
+        cells
+    }
+
+    pub fn union_with(&mut self, other: &DirectionBitGrid) {
+        self.check_same_size(other);
+        self.bits.union_with(&other.bits);
+    }
+
+    pub fn intersect_with(&mut self, other: &DirectionBitGrid) {
+        self.check_same_size(other);
+        self.bits.intersect_with(&other.bits);
+    }
+
+    fn check_same_size(&self, other: &DirectionBitGrid) {
+        if (self.width, self.height) != (other.width, other.height) {
+            panic!(
+                "Combining grid with bounds ({0}, {1}) and grid with bounds ({2}, {3})",
+                self.width, self.height, other.width, other.height
+            )
+        }
+    }
+
+    fn index(&self, coords: &Coords, direction: &GridDirection) -> usize {
+        let bit = match direction {
+            GridDirection::North => 0,
+            GridDirection::East => 1,
+            GridDirection::South => 2,
+            GridDirection::West => 3,
+            _ => panic!("Unsupported grid direction {direction:?}"),
+        };
+
+        if self.in_bounds(coords.x, coords.y) {
+            (coords.y * self.width + coords.x) as usize * 4 + bit
+        } else {
+            panic!(
+                "Accessing grid with bounds ({0}, {1}) at ({2}, {3})",
+                self.width, self.height, coords.x, coords.y
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_test_and_count() {
+        let mut grid = BitGrid::new(70, 3);
+
+        assert!(grid.insert(&Coords { x: 69, y: 0 }));
+        assert!(!grid.insert(&Coords { x: 69, y: 0 }));
+        grid.set(1, 2, true);
+
+        assert!(grid.get(69, 0));
+        assert!(!grid.get(68, 0));
+        assert_eq!(grid.count_ones(), 2);
+        assert_eq!(
+            grid.iter_ones().collect::<Vec<_>>(),
+            vec![Coords { x: 69, y: 0 }, Coords { x: 1, y: 2 }]
+        );
+
+        grid.clear();
+        assert_eq!(grid.count_ones(), 0);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let mut a = BitGrid::new(3, 2);
+        let mut b = BitGrid::new(3, 2);
+        a.set(0, 0, true);
+        a.set(1, 1, true);
+        b.set(1, 1, true);
+        b.set(2, 0, true);
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        a.intersect_with(&b);
+
+        assert_eq!(union.to_string(), "#.#\n.#.\n");
+        assert_eq!(a.to_string(), "...\n.#.\n");
+    }
+
+    #[test]
+    fn directions_per_cell() {
+        let mut grid = DirectionBitGrid::new(2, 2);
+        let cell = Coords { x: 1, y: 0 };
+
+        assert!(grid.insert(&cell, &GridDirection::North));
+        assert!(grid.insert(&cell, &GridDirection::West));
+        assert!(!grid.insert(&cell, &GridDirection::North));
+
+        assert!(grid.contains(&cell, &GridDirection::West));
+        assert!(!grid.contains(&cell, &GridDirection::East));
+        assert_eq!(grid.count_ones(), 2);
+        assert_eq!(grid.cells().to_string(), ".#\n..\n");
+    }
+}
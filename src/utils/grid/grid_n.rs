@@ -0,0 +1,336 @@
+use std::{
+    fmt,
+    ops::{Add, Sub},
+};
+
+use super::{Grid, GridView};
+
+/// Coordinates in `D` dimensions, starting with `x`, `y` and `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoordsN<const D: usize>(pub [i32; D]);
+
+pub type Coords3 = CoordsN<3>;
+
+impl<const D: usize> CoordsN<D> {
+    pub fn manhattan(&self, other: &CoordsN<D>) -> u32 {
+        self.0.iter().zip(other.0).map(|(a, b)| a.abs_diff(b)).sum()
+    }
+}
+
+impl Coords3 {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        CoordsN([x, y, z])
+    }
+}
+
+impl<const D: usize> Add for CoordsN<D> {
+    type Output = CoordsN<D>;
+
+    fn add(self, other: CoordsN<D>) -> CoordsN<D> {
+        CoordsN(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<const D: usize> Sub for CoordsN<D> {
+    type Output = CoordsN<D>;
+
+    fn sub(self, other: CoordsN<D>) -> CoordsN<D> {
+        CoordsN(std::array::from_fn(|axis| self.0[axis] - other.0[axis]))
+    }
+}
+
+/// Dense grid in `D` dimensions, stored with `x` varying fastest, then `y`, `z` and so on.
+///
+/// Every `xy` plane is stored contiguously, so it can be viewed as a 2D [`GridView`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridN<T, const D: usize> {
+    pub dims: [i32; D],
+    data: Vec<T>,
+}
+
+pub type Grid3<T> = GridN<T, 3>;
+
+impl<T: Clone, const D: usize> GridN<T, D> {
+    pub fn from_default(dims: [usize; D], default_value: T) -> Self {
+        check_dims(&dims);
+
+        GridN {
+            dims: dims.map(|size| size as i32),
+            data: vec![default_value; dims.iter().product()],
+        }
+    }
+
+    pub fn in_bounds(&self, coords: &CoordsN<D>) -> bool {
+        coords
+            .0
+            .iter()
+            .zip(self.dims)
+            .all(|(&position, size)| (0..size).contains(&position))
+    }
+
+    pub fn get(&self, coords: &CoordsN<D>) -> &T {
+        let index = self.checked_index(coords);
+        &self.data[index]
+    }
+
+    pub fn try_get(&self, coords: &CoordsN<D>) -> Option<&T> {
+        if self.in_bounds(coords) {
+            Some(&self.data[self.index(coords)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coords: &CoordsN<D>) -> &mut T {
+        let index = self.checked_index(coords);
+        &mut self.data[index]
+    }
+
+    pub fn set(&mut self, coords: &CoordsN<D>, value: T) {
+        *self.get_mut(coords) = value;
+    }
+
+    /// Cells with their coordinates, with `x` varying fastest.
+    pub fn enumerate_all(&self) -> impl Iterator<Item = (CoordsN<D>, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.coords(index), value))
+    }
+
+    /// In-bounds neighbours differing in a single axis by one, 6 of them in 3D.
+    pub fn orthogonal_neighbors(
+        &self,
+        coords: &CoordsN<D>,
+    ) -> impl Iterator<Item = (CoordsN<D>, &T)> + '_ {
+        let offsets = (0..D).flat_map(|axis| {
+            [-1, 1].map(|step| {
+                let mut offset = [0; D];
+                offset[axis] = step;
+                CoordsN(offset)
+            })
+        });
+
+        self.neighbors(*coords, offsets.collect())
+    }
+
+    /// In-bounds neighbours differing in any axes by at most one, 26 of them in 3D.
+    pub fn all_neighbors(
+        &self,
+        coords: &CoordsN<D>,
+    ) -> impl Iterator<Item = (CoordsN<D>, &T)> + '_ {
+        let offsets = (0..3usize.pow(D as u32))
+            .map(|mut code| {
+                CoordsN(std::array::from_fn(|_| {
+                    let step = (code % 3) as i32 - 1;
+                    code /= 3;
+                    step
+                }))
+            })
+            .filter(|offset| offset.0 != [0; D]);
+
+        self.neighbors(*coords, offsets.collect())
+    }
+
+    fn neighbors(
+        &self,
+        coords: CoordsN<D>,
+        offsets: Vec<CoordsN<D>>,
+    ) -> impl Iterator<Item = (CoordsN<D>, &T)> + '_ {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbor = coords + offset;
+            self.try_get(&neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// The `xy` plane at the given positions on the remaining axes, like `[z]` in 3D.
+    pub fn layer(&self, higher: &[i32]) -> GridView<'_, T> {
+        if higher.len() != D - 2 {
+            panic!("Invalid layer {higher:?} for grid with {D} dimensions")
+        }
+
+        let mut start = [0; D];
+        start[2..].copy_from_slice(higher);
+        let start = self.checked_index(&CoordsN(start));
+        let (width, height) = (self.dims[0], self.dims[1]);
+
+        GridView {
+            cells: &self.data[start..start + (width * height) as usize],
+            stride: width,
+            grid_x: 0,
+            grid_y: 0,
+            width,
+            height,
+        }
+    }
+
+    /// Positions on the axes beyond `x` and `y` of every layer, in storage order.
+    pub fn layers(&self) -> impl Iterator<Item = Vec<i32>> + '_ {
+        let layer_size = (self.dims[0] * self.dims[1]) as usize;
+        (0..self.data.len() / layer_size.max(1))
+            .map(move |layer| self.coords(layer * layer_size).0[2..].to_vec())
+    }
+
+    fn index(&self, coords: &CoordsN<D>) -> usize {
+        let mut index = 0;
+        for axis in (0..D).rev() {
+            index = index * self.dims[axis] as usize + coords.0[axis] as usize;
+        }
+        index
+    }
+
+    fn checked_index(&self, coords: &CoordsN<D>) -> usize {
+        if self.in_bounds(coords) {
+            self.index(coords)
+        } else {
+            panic!(
+                "Accessing grid with bounds {:?} at {:?}",
+                self.dims, coords.0
+            )
+        }
+    }
+
+    fn coords(&self, mut index: usize) -> CoordsN<D> {
+        CoordsN(std::array::from_fn(|axis| {
+            let size = self.dims[axis] as usize;
+            let position = index % size;
+            index /= size;
+            position as i32
+        }))
+    }
+}
+
+impl<T: Clone> Grid3<T> {
+    /// Stacks equally sized 2D grids along `z`.
+    pub fn from_layers(layers: Vec<Grid<T>>) -> Self {
+        let Some(first) = layers.first() else {
+            panic!("Cannot build a grid without layers")
+        };
+        let (width, height) = (first.width, first.height);
+
+        if let Some(z) = layers
+            .iter()
+            .position(|layer| (layer.width, layer.height) != (width, height))
+        {
+            panic!("Invalid layer {z}, expected bounds ({width}, {height})")
+        }
+
+        check_dims(&[width as usize, height as usize, layers.len()]);
+
+        GridN {
+            dims: [width, height, layers.len() as i32],
+            data: layers.into_iter().flat_map(|layer| layer.data).collect(),
+        }
+    }
+}
+
+/// Every size must be positive, as positions are recovered from indices by dividing by it.
+fn check_dims<const D: usize>(dims: &[usize; D]) {
+    if D < 2 {
+        panic!("Invalid grid dimension {D}, expected at least 2")
+    }
+    if let Some(size) = dims
+        .iter()
+        .find(|&&size| size == 0 || size > i32::MAX as usize)
+    {
+        panic!("Invalid grid size {size}")
+    }
+}
+
+const HIGHER_AXES: [&str; 2] = ["z", "w"];
+
+/// Renders every `xy` layer under a header with its position, like `z=1`.
+impl<T: Clone + fmt::Display, const D: usize> fmt::Display for GridN<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, higher) in self.layers().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let header: Vec<String> = higher
+                .iter()
+                .enumerate()
+                .map(|(axis, position)| match HIGHER_AXES.get(axis) {
+                    Some(name) => format!("{name}={position}"),
+                    None => format!("axis{}={position}", axis + 2),
+                })
+                .collect();
+            writeln!(f, "{}", header.join(", "))?;
+
+            for row in self.layer(&higher).rows() {
+                for (_, value) in row {
+                    write!(f, "{value}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    #[test]
+    fn access_and_bounds() {
+        let mut cube = Grid3::from_default([2, 3, 4], 0);
+        let corner = Coords3::new(1, 2, 3);
+
+        cube.set(&corner, 7);
+        *cube.get_mut(&Coords3::new(0, 1, 2)) += 1;
+
+        assert_eq!(cube.get(&corner), &7);
+        assert!(cube.in_bounds(&corner));
+        assert_eq!(cube.try_get(&Coords3::new(2, 0, 0)), None);
+        assert_eq!(cube.try_get(&Coords3::new(0, 0, -1)), None);
+        assert_eq!(
+            cube.enumerate_all()
+                .filter(|(_, &v)| v > 0)
+                .map(|(c, _)| c)
+                .collect::<Vec<_>>(),
+            vec![Coords3::new(0, 1, 2), corner]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid grid size 0")]
+    fn zero_size_is_rejected() {
+        Grid3::from_default([2, 0, 4], 0);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let cube = Grid3::from_default([3, 3, 3], ());
+        let center = Coords3::new(1, 1, 1);
+        let corner = Coords3::new(0, 0, 0);
+
+        assert_eq!(cube.orthogonal_neighbors(&center).count(), 6);
+        assert_eq!(cube.all_neighbors(&center).count(), 26);
+        assert_eq!(cube.orthogonal_neighbors(&corner).count(), 3);
+        assert_eq!(cube.all_neighbors(&corner).count(), 7);
+        assert!(cube
+            .all_neighbors(&center)
+            .all(|(c, _)| c.manhattan(&center) <= 3 && c != center));
+
+        let hypercube = GridN::from_default([3; 4], ());
+        assert_eq!(hypercube.all_neighbors(&CoordsN([1; 4])).count(), 80);
+    }
+
+    #[test]
+    fn layers_and_display() {
+        let layers = ["#.\n..\n", ".#\n#.\n"].map(|text| grid::<char>(text).unwrap());
+        let cube = Grid3::from_layers(layers.to_vec());
+
+        let layer = cube.layer(&[1]);
+        assert_eq!(layer.get(1, 0), Some(&'#'));
+        assert_eq!(layer.get(1, 1), Some(&'.'));
+        assert_eq!(cube.to_string(), "z=0\n#.\n..\n\nz=1\n.#\n#.\n");
+
+        let mut tesseract = GridN::from_default([1, 1, 1, 2], 'a');
+        tesseract.set(&CoordsN([0, 0, 0, 1]), 'b');
+        assert_eq!(tesseract.to_string(), "z=0, w=0\na\n\nz=0, w=1\nb\n");
+    }
+}
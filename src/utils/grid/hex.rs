@@ -0,0 +1,489 @@
+//! Hexagonal grids in axial coordinates.
+//!
+//! Axial coordinates `(q, r)` don't depend on how the hexagons are drawn. The [`HexLayout`]
+//! only decides which compass names the six neighbours get.
+
+use std::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+use super::{Coords, Grid, GridDirection};
+use crate::utils::parse::{ParseCell, ParseError};
+
+/// Axial hex coordinates. The third cube coordinate is `s = -q - r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexCoords {
+    pub q: i32,
+    pub r: i32,
+}
+
+/// Offsets of the six neighbours, going around clockwise.
+const NEIGHBOR_OFFSETS: [HexCoords; 6] = [
+    HexCoords { q: 1, r: -1 },
+    HexCoords { q: 1, r: 0 },
+    HexCoords { q: 0, r: 1 },
+    HexCoords { q: -1, r: 1 },
+    HexCoords { q: -1, r: 0 },
+    HexCoords { q: 0, r: -1 },
+];
+
+impl HexCoords {
+    pub fn new(q: i32, r: i32) -> Self {
+        HexCoords { q, r }
+    }
+
+    /// Cube coordinates `[q, r, s]`, which always sum to zero.
+    pub fn cube(&self) -> [i32; 3] {
+        [self.q, self.r, -self.q - self.r]
+    }
+
+    pub fn from_cube([q, r, s]: [i32; 3]) -> Self {
+        debug_assert_eq!(q + r + s, 0, "Cube coordinates must sum to zero");
+        HexCoords { q, r }
+    }
+
+    /// Number of steps between the two hexes.
+    pub fn distance(&self, other: &HexCoords) -> u32 {
+        let difference = (*self - *other).cube();
+        difference.iter().map(|d| d.unsigned_abs()).sum::<u32>() / 2
+    }
+
+    pub fn neighbors(&self) -> [HexCoords; 6] {
+        NEIGHBOR_OFFSETS.map(|offset| *self + offset)
+    }
+
+    /// Hexes at exactly `radius` steps, going around clockwise.
+    pub fn ring(&self, radius: u32) -> Vec<HexCoords> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        // Start at the corner in the direction of the last offset and walk each side.
+        let mut current = *self + NEIGHBOR_OFFSETS[4] * radius as i32;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+
+        for offset in NEIGHBOR_OFFSETS {
+            for _ in 0..radius {
+                ring.push(current);
+                current = current + offset;
+            }
+        }
+
+        ring
+    }
+
+    /// Hexes within `radius` steps, the center first and then ring after ring.
+    pub fn spiral(&self, radius: u32) -> Vec<HexCoords> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Hexes on the straight line to `other`, both ends included.
+    pub fn line_to(&self, other: &HexCoords) -> Vec<HexCoords> {
+        let steps = self.distance(other);
+        if steps == 0 {
+            return vec![*self];
+        }
+
+        // Nudge the ends slightly, so that points on hex edges round consistently.
+        let nudge = [1e-6, 2e-6, -3e-6];
+        let (from, to) = (self.cube(), other.cube());
+
+        (0..=steps)
+            .map(|step| {
+                let t = step as f64 / steps as f64;
+                let point: [f64; 3] = std::array::from_fn(|axis| {
+                    let (a, b) = (
+                        from[axis] as f64 + nudge[axis],
+                        to[axis] as f64 + nudge[axis],
+                    );
+                    a + (b - a) * t
+                });
+                cube_round(point)
+            })
+            .collect()
+    }
+}
+
+fn cube_round(point: [f64; 3]) -> HexCoords {
+    let mut rounded = point.map(f64::round);
+    let errors: [f64; 3] = std::array::from_fn(|axis| (rounded[axis] - point[axis]).abs());
+
+    // Recompute the coordinate with the largest rounding error from the other two.
+    if errors[0] > errors[1] && errors[0] > errors[2] {
+        rounded[0] = -rounded[1] - rounded[2];
+    } else if errors[1] > errors[2] {
+        rounded[1] = -rounded[0] - rounded[2];
+    } else {
+        rounded[2] = -rounded[0] - rounded[1];
+    }
+
+    HexCoords::from_cube(rounded.map(|c| c as i32))
+}
+
+impl Add for HexCoords {
+    type Output = HexCoords;
+
+    fn add(self, other: HexCoords) -> HexCoords {
+        HexCoords {
+            q: self.q + other.q,
+            r: self.r + other.r,
+        }
+    }
+}
+
+impl Sub for HexCoords {
+    type Output = HexCoords;
+
+    fn sub(self, other: HexCoords) -> HexCoords {
+        HexCoords {
+            q: self.q - other.q,
+            r: self.r - other.r,
+        }
+    }
+}
+
+impl Mul<i32> for HexCoords {
+    type Output = HexCoords;
+
+    fn mul(self, factor: i32) -> HexCoords {
+        HexCoords {
+            q: self.q * factor,
+            r: self.r * factor,
+        }
+    }
+}
+
+/// How hexes are drawn, which decides the compass names of the six directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexLayout {
+    /// Flat edges at the top, neighbours `n`, `ne`, `se`, `s`, `sw` and `nw`.
+    FlatTop,
+    /// Pointed tops with rows of hexes, neighbours `ne`, `e`, `se`, `sw`, `w` and `nw`.
+    PointyTop,
+}
+
+impl HexLayout {
+    /// The six directions, clockwise from north or north-east.
+    pub fn directions(&self) -> [GridDirection; 6] {
+        use GridDirection::*;
+
+        match self {
+            HexLayout::FlatTop => [North, NorthEast, SouthEast, South, SouthWest, NorthWest],
+            HexLayout::PointyTop => [NorthEast, East, SouthEast, SouthWest, West, NorthWest],
+        }
+    }
+
+    /// Axial offset of a step in `direction`, `None` for directions the layout doesn't have.
+    pub fn offset(&self, direction: &GridDirection) -> Option<HexCoords> {
+        use GridDirection::*;
+
+        let offset = match (self, direction) {
+            (HexLayout::FlatTop, North) => (0, -1),
+            (HexLayout::FlatTop, NorthEast) => (1, -1),
+            (HexLayout::FlatTop, SouthEast) => (1, 0),
+            (HexLayout::FlatTop, South) => (0, 1),
+            (HexLayout::FlatTop, SouthWest) => (-1, 1),
+            (HexLayout::FlatTop, NorthWest) => (-1, 0),
+            (HexLayout::PointyTop, NorthEast) => (1, -1),
+            (HexLayout::PointyTop, East) => (1, 0),
+            (HexLayout::PointyTop, SouthEast) => (0, 1),
+            (HexLayout::PointyTop, SouthWest) => (-1, 1),
+            (HexLayout::PointyTop, West) => (-1, 0),
+            (HexLayout::PointyTop, NorthWest) => (0, -1),
+            _ => return None,
+        };
+
+        Some(HexCoords::new(offset.0, offset.1))
+    }
+
+    /// Moves one step in `direction`.
+    pub fn step(&self, coords: &HexCoords, direction: &GridDirection) -> Option<HexCoords> {
+        self.offset(direction).map(|offset| *coords + offset)
+    }
+
+    /// Parses steps like `ne,se,n` or `esenee`, separated by commas, whitespace or nothing.
+    pub fn parse_steps(
+        &self,
+        input: &str,
+        fragment: &str,
+    ) -> Result<Vec<GridDirection>, ParseError> {
+        let mut steps = Vec::new();
+        let mut rest = fragment;
+
+        loop {
+            rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+            if rest.is_empty() {
+                return Ok(steps);
+            }
+
+            let (name, direction) = ["ne", "nw", "se", "sw", "n", "e", "s", "w"]
+                .into_iter()
+                .find(|name| rest.starts_with(name))
+                .map(|name| (&rest[..name.len()], direction_named(name)))
+                .ok_or_else(|| ParseError::at_fragment(input, rest, "expected a hex direction"))?;
+
+            if self.offset(&direction).is_none() {
+                return Err(ParseError::at_fragment(
+                    input,
+                    name,
+                    format!("direction `{name}` does not exist in the {self:?} layout"),
+                ));
+            }
+
+            steps.push(direction);
+            rest = &rest[name.len()..];
+        }
+    }
+}
+
+fn direction_named(name: &str) -> GridDirection {
+    match name {
+        "n" => GridDirection::North,
+        "ne" => GridDirection::NorthEast,
+        "e" => GridDirection::East,
+        "se" => GridDirection::SouthEast,
+        "s" => GridDirection::South,
+        "sw" => GridDirection::SouthWest,
+        "w" => GridDirection::West,
+        "nw" => GridDirection::NorthWest,
+        _ => unreachable!("Unknown direction {name}"),
+    }
+}
+
+/// Bounded hex grid with rows of pointy-top hexes, stored in a [`Grid`] of offset rows.
+///
+/// Every other row is shifted half a hex to the east. Row `r` and column `c` of the storage are
+/// axial `r` and `q = c - (r ± (r & 1)) / 2`, depending on which rows are shifted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HexGrid<T> {
+    cells: Grid<T>,
+    odd_rows_shifted: bool,
+}
+
+impl<T: Clone> HexGrid<T> {
+    pub fn from_default(width: usize, height: usize, odd_rows_shifted: bool, value: T) -> Self {
+        HexGrid {
+            cells: Grid::from_default(width, height, value),
+            odd_rows_shifted,
+        }
+    }
+
+    /// The underlying storage, indexed by offset column and row.
+    pub fn offset_grid(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    pub fn to_offset(&self, coords: &HexCoords) -> Coords {
+        let shift = if self.odd_rows_shifted {
+            coords.r - (coords.r & 1)
+        } else {
+            coords.r + (coords.r & 1)
+        };
+
+        Coords {
+            x: coords.q + shift / 2,
+            y: coords.r,
+        }
+    }
+
+    pub fn from_offset(&self, coords: &Coords) -> HexCoords {
+        let shift = if self.odd_rows_shifted {
+            coords.y - (coords.y & 1)
+        } else {
+            coords.y + (coords.y & 1)
+        };
+
+        HexCoords {
+            q: coords.x - shift / 2,
+            r: coords.y,
+        }
+    }
+
+    pub fn in_bounds(&self, coords: &HexCoords) -> bool {
+        let offset = self.to_offset(coords);
+        self.cells.in_bounds(offset.x, offset.y)
+    }
+
+    pub fn get(&self, coords: &HexCoords) -> Option<&T> {
+        let offset = self.to_offset(coords);
+        self.cells.try_get(offset.x, offset.y)
+    }
+
+    pub fn get_mut(&mut self, coords: &HexCoords) -> Option<&mut T> {
+        let offset = self.to_offset(coords);
+        self.cells.try_get_mut(offset.x, offset.y)
+    }
+
+    pub fn set(&mut self, coords: &HexCoords, value: T) {
+        let offset = self.to_offset(coords);
+        self.cells.set_by_coords(&offset, value);
+    }
+
+    /// In-bounds neighbours of `coords`, clockwise from north-east.
+    pub fn neighbors(&self, coords: &HexCoords) -> impl Iterator<Item = (HexCoords, &T)> + '_ {
+        coords
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| self.get(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// Cells with their axial coordinates, row by row.
+    pub fn enumerate_all(&self) -> impl Iterator<Item = (HexCoords, &T)> {
+        self.cells
+            .enumerate_all()
+            .map(|(x, y, value)| (self.from_offset(&Coords { x, y }), value))
+    }
+}
+
+impl<T: ParseCell + Clone> HexGrid<T> {
+    /// Parses rows of hexes separated by spaces, where every other row is indented, like
+    /// ```text
+    ///  a b c
+    /// d e f
+    /// ```
+    pub fn from_offset_rows(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let indent = |line: &str| line.chars().take_while(|c| c.is_whitespace()).count();
+        let odd_rows_shifted = match lines[..] {
+            [first, second, ..] => indent(second) > indent(first),
+            _ => true,
+        };
+
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| !c.is_whitespace())
+                    .map(|(x, c)| {
+                        T::from_char(c)
+                            .ok_or_else(|| ParseError::at(y, x, format!("unsupported cell `{c}`")))
+                    })
+                    .collect::<Result<Vec<T>, _>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
+
+        let Some(width) = rows.first().map(Vec::len) else {
+            return Err(ParseError::at(0, 0, "expected a hex grid"));
+        };
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(ParseError::at(
+                y,
+                0,
+                format!(
+                    "expected {width} hexes in every row, found {}",
+                    rows[y].len()
+                ),
+            ));
+        }
+
+        Ok(HexGrid {
+            cells: Grid::from_lines(rows),
+            odd_rows_shifted,
+        })
+    }
+}
+
+/// Renders offset rows, with hexes separated by spaces and shifted rows indented.
+impl<T: Clone + fmt::Display> fmt::Display for HexGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.cells.rows().enumerate() {
+            if (y % 2 == 1) == self.odd_rows_shifted {
+                write!(f, " ")?;
+            }
+
+            let cells: Vec<String> = row.map(|(_, value)| value.to_string()).collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(layout: HexLayout, steps: &str) -> HexCoords {
+        layout
+            .parse_steps(steps, steps)
+            .unwrap()
+            .iter()
+            .fold(HexCoords::new(0, 0), |position, direction| {
+                layout.step(&position, direction).unwrap()
+            })
+    }
+
+    #[test]
+    fn step_lists() {
+        let origin = HexCoords::new(0, 0);
+        let distance = |steps| walk(HexLayout::FlatTop, steps).distance(&origin);
+
+        assert_eq!(distance("ne,ne,ne"), 3);
+        assert_eq!(distance("ne,ne,sw,sw"), 0);
+        assert_eq!(distance("ne,ne,s,s"), 2);
+        assert_eq!(distance("se,sw,se,sw,sw"), 3);
+        assert_eq!(walk(HexLayout::PointyTop, "nwwswee"), origin);
+
+        let input = "n,e";
+        assert_eq!(
+            HexLayout::FlatTop.parse_steps(input, input),
+            Err(ParseError::at(
+                0,
+                2,
+                "direction `e` does not exist in the FlatTop layout"
+            ))
+        );
+    }
+
+    #[test]
+    fn rings_spirals_and_lines() {
+        let center = HexCoords::new(2, -1);
+
+        let ring = center.ring(2);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|hex| hex.distance(&center) == 2));
+        assert_eq!(center.spiral(2).len(), 19);
+        assert_eq!(center.spiral(2)[0], center);
+
+        let end = HexCoords::new(-2, 3);
+        let line = center.line_to(&end);
+        assert_eq!(line.len(), 5);
+        assert_eq!((line[0], line[4]), (center, end));
+        assert!(line.windows(2).all(|pair| pair[0].distance(&pair[1]) == 1));
+    }
+
+    #[test]
+    fn offset_rows() {
+        let grid: HexGrid<char> = HexGrid::from_offset_rows("a b c\n d e f\ng h i\n").unwrap();
+
+        let e = HexCoords::new(1, 1);
+        assert_eq!(grid.get(&e), Some(&'e'));
+        let mut neighbors: Vec<char> = grid.neighbors(&e).map(|(_, &c)| c).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!['b', 'c', 'd', 'f', 'h', 'i']);
+        assert_eq!(grid.to_string(), "a b c\n d e f\ng h i\n");
+
+        let shifted_first: HexGrid<char> = HexGrid::from_offset_rows(" a b\nc d\n").unwrap();
+        assert_eq!(shifted_first.neighbors(&HexCoords::new(0, 1)).count(), 3);
+        assert_eq!(shifted_first.to_string(), " a b\nc d\n");
+    }
+
+    #[derive(Clone, Debug)]
+    struct Digit;
+
+    impl ParseCell for Digit {
+        fn from_char(c: char) -> Option<Self> {
+            c.is_ascii_digit().then_some(Digit)
+        }
+    }
+
+    #[test]
+    fn offset_rows_report_character_columns() {
+        // The ideographic space takes three bytes but is a single column.
+        let error = HexGrid::<Digit>::from_offset_rows("1\u{3000}2\u{3000}x\n").err();
+
+        assert_eq!(error, Some(ParseError::at(0, 4, "unsupported cell `x`")));
+    }
+}
@@ -0,0 +1,136 @@
+use super::{Coords, Grid, GridDirection, GridView};
+
+/// Cells along a straight line through a view, with their coordinates relative to the view.
+pub struct GridLine<'a, T> {
+    view: GridView<'a, T>,
+    current: Coords,
+    change: Coords,
+}
+
+impl<'a, T: Clone> Iterator for GridLine<'a, T> {
+    type Item = (Coords, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.view.get(self.current.x, self.current.y)?;
+        let coords = self.current;
+        self.current = self.current + self.change;
+        Some((coords, item))
+    }
+}
+
+impl<'a, T: Clone> GridView<'a, T> {
+    /// Every maximal line of cells running in `direction`, starting at the edge of the view.
+    pub fn lines(&self, direction: GridDirection) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        let view = self.clone();
+        let (width, height) = (self.width, self.height);
+        let change = direction.to_coords();
+
+        (0..height)
+            .flat_map(move |y| (0..width).map(move |x| Coords { x, y }))
+            .filter(move |start| {
+                let previous = *start - change;
+                !(0..width).contains(&previous.x) || !(0..height).contains(&previous.y)
+            })
+            .map(move |start| GridLine {
+                view: view.clone(),
+                current: start,
+                change,
+            })
+    }
+
+    /// Rows from top to bottom, each running east.
+    pub fn rows(&self) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        self.lines(GridDirection::East)
+    }
+
+    /// Columns from left to right, each running south.
+    pub fn columns(&self) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        self.lines(GridDirection::South)
+    }
+
+    /// Diagonals running south-east.
+    pub fn diagonals(&self) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        self.lines(GridDirection::SouthEast)
+    }
+
+    /// Diagonals running south-west.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        self.lines(GridDirection::SouthWest)
+    }
+
+    /// Lines in all eight directions, so every straight sequence of cells is read both ways.
+    pub fn all_lines(&self) -> impl Iterator<Item = GridLine<'a, T>> + 'a {
+        let view = self.clone();
+        GridDirection::all().flat_map(move |direction| view.lines(direction))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Every maximal line of cells running in `direction`, starting at the edge of the grid.
+    pub fn lines(&self, direction: GridDirection) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().lines(direction)
+    }
+
+    /// Rows from top to bottom, each running east.
+    pub fn rows(&self) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().rows()
+    }
+
+    /// Columns from left to right, each running south.
+    pub fn columns(&self) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().columns()
+    }
+
+    /// Diagonals running south-east.
+    pub fn diagonals(&self) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().diagonals()
+    }
+
+    /// Diagonals running south-west.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().anti_diagonals()
+    }
+
+    /// Lines in all eight directions, so every straight sequence of cells is read both ways.
+    pub fn all_lines(&self) -> impl Iterator<Item = GridLine<'_, T>> {
+        self.full_view().all_lines()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    fn texts<'a>(lines: impl Iterator<Item = GridLine<'a, char>>) -> Vec<String> {
+        lines.map(|line| line.map(|(_, c)| *c).collect()).collect()
+    }
+
+    #[test]
+    fn lines_cover_grid() {
+        let letters: Grid<char> = grid("abc\ndef\n").unwrap();
+
+        assert_eq!(texts(letters.rows()), vec!["abc", "def"]);
+        assert_eq!(texts(letters.columns()), vec!["ad", "be", "cf"]);
+        assert_eq!(texts(letters.diagonals()), vec!["ae", "bf", "c", "d"]);
+        assert_eq!(texts(letters.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+        assert_eq!(
+            letters.all_lines().map(|line| line.count()).sum::<usize>(),
+            8 * 6
+        );
+    }
+
+    #[test]
+    fn view_lines_are_relative() {
+        let letters: Grid<char> = grid("abc\ndef\nghi\n").unwrap();
+        let view = letters.view(1, 1, 2, 2);
+
+        let first: Vec<(Coords, &char)> = view.lines(GridDirection::West).next().unwrap().collect();
+
+        assert_eq!(
+            first,
+            vec![(Coords { x: 1, y: 0 }, &'f'), (Coords { x: 0, y: 0 }, &'e')]
+        );
+    }
+}
@@ -0,0 +1,764 @@
+#![allow(dead_code)]
+
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+pub use bits::{BitGrid, DirectionBitGrid};
+pub use grid_n::{Coords3, CoordsN, Grid3, GridN};
+pub use hex::{HexCoords, HexGrid, HexLayout};
+pub use lines::GridLine;
+pub use pattern::{Orientation, Pattern, PatternMatch};
+pub use region::{Connectivity, Region, RegionLabel, Regions};
+pub use sparse::{Bounds, SparseGrid};
+
+mod bits;
+mod grid_n;
+mod hex;
+mod lines;
+mod pattern;
+mod region;
+mod sparse;
+mod torus;
+mod transform;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coords {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coords {
+    pub fn manhattan(&self, other: &Coords) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Coords) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Add for Coords {
+    type Output = Coords;
+
+    fn add(self, other: Coords) -> Coords {
+        Coords {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for Coords {
+    type Output = Coords;
+
+    fn sub(self, other: Coords) -> Coords {
+        Coords {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Mul<i32> for Coords {
+    type Output = Coords;
+
+    fn mul(self, factor: i32) -> Coords {
+        Coords {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl Neg for Coords {
+    type Output = Coords;
+
+    fn neg(self) -> Coords {
+        Coords {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridDirection {
+    East,
+    West,
+    South,
+    North,
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+}
+
+const CARDINAL: [GridDirection; 4] = [
+    GridDirection::North,
+    GridDirection::East,
+    GridDirection::South,
+    GridDirection::West,
+];
+
+const DIAGONAL: [GridDirection; 4] = [
+    GridDirection::NorthEast,
+    GridDirection::SouthEast,
+    GridDirection::SouthWest,
+    GridDirection::NorthWest,
+];
+
+const ALL: [GridDirection; 8] = [
+    GridDirection::North,
+    GridDirection::NorthEast,
+    GridDirection::East,
+    GridDirection::SouthEast,
+    GridDirection::South,
+    GridDirection::SouthWest,
+    GridDirection::West,
+    GridDirection::NorthWest,
+];
+
+impl GridDirection {
+    /// All eight directions, clockwise from north.
+    pub fn all() -> impl Iterator<Item = GridDirection> {
+        ALL.into_iter()
+    }
+
+    /// The four horizontal and vertical directions, clockwise from north.
+    pub fn cardinal() -> impl Iterator<Item = GridDirection> {
+        CARDINAL.into_iter()
+    }
+
+    /// The four diagonal directions, clockwise from north-east.
+    pub fn diagonal() -> impl Iterator<Item = GridDirection> {
+        DIAGONAL.into_iter()
+    }
+
+    /// Direction after a 90° clockwise turn.
+    pub fn turn_right(&self) -> GridDirection {
+        self.rotate(2)
+    }
+
+    /// Direction after a 90° counter-clockwise turn.
+    pub fn turn_left(&self) -> GridDirection {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> GridDirection {
+        self.rotate(4)
+    }
+
+    /// Direction after a 45° clockwise turn, from a cardinal to a diagonal one and back.
+    pub fn rotate_right_45(&self) -> GridDirection {
+        self.rotate(1)
+    }
+
+    /// Direction after a 45° counter-clockwise turn.
+    pub fn rotate_left_45(&self) -> GridDirection {
+        self.rotate(7)
+    }
+
+    fn rotate(&self, eighths: usize) -> GridDirection {
+        let index = ALL.iter().position(|d| d == self).unwrap();
+        ALL[(index + eighths) % ALL.len()]
+    }
+
+    /// Offset of a single step in this direction, with y growing southwards.
+    pub fn to_coords(&self) -> Coords {
+        match self {
+            GridDirection::East => Coords { x: 1, y: 0 },
+            GridDirection::West => Coords { x: -1, y: 0 },
+            GridDirection::South => Coords { x: 0, y: 1 },
+            GridDirection::North => Coords { x: 0, y: -1 },
+            GridDirection::NorthWest => Coords { x: -1, y: -1 },
+            GridDirection::NorthEast => Coords { x: 1, y: -1 },
+            GridDirection::SouthEast => Coords { x: 1, y: 1 },
+            GridDirection::SouthWest => Coords { x: -1, y: 1 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: i32,
+    pub height: i32,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn from_default(width: usize, height: usize, default_value: T) -> Self {
+        check_dimensions(width, height);
+        let item_count = width * height;
+
+        Grid {
+            width: width.try_into().unwrap(),
+            height: height.try_into().unwrap(),
+            data: vec![default_value; item_count],
+        }
+    }
+
+    pub fn from_lines(lines: Vec<Vec<T>>) -> Self {
+        let width = lines.first().map_or(0, Vec::len);
+        let height = lines.len();
+        check_dimensions(width, height);
+
+        if let Some(y) = lines.iter().position(|line| line.len() != width) {
+            panic!("Invalid grid line {y} with length {}, expected {width}", lines[y].len())
+        }
+
+        let data = lines.concat();
+
+        Grid {
+            width: width.try_into().unwrap(),
+            height: height.try_into().unwrap(),
+            data,
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> &T {
+        let index = self.index(x, y);
+        &self.data[index]
+    }
+
+    pub fn try_get(&self, x: i32, y: i32) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            let index = self.index(x, y);
+            Some(&self.data[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_by_coords(&self, coords: &Coords) -> &T {
+        self.get(coords.x, coords.y)
+    }
+
+    pub fn set(&mut self, x: i32, y: i32, value: T) {
+        if self.in_bounds(x, y) {
+            let index = self.index(x, y);
+            self.data[index] = value
+        } else {
+            panic!(
+                "Modifying grid with bounds ({0}, {1}) at ({x}, {y})",
+                self.width, self.height
+            )
+        }
+    }
+
+    pub fn set_by_coords(&mut self, coords: &Coords, value: T) {
+        self.set(coords.x, coords.y, value);
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> &mut T {
+        if self.in_bounds(x, y) {
+            let index = self.index(x, y);
+            &mut self.data[index]
+        } else {
+            panic!(
+                "Modifying grid with bounds ({0}, {1}) at ({x}, {y})",
+                self.width, self.height
+            )
+        }
+    }
+
+    pub fn try_get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            let index = self.index(x, y);
+            Some(&mut self.data[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut_by_coords(&mut self, coords: &Coords) -> &mut T {
+        self.get_mut(coords.x, coords.y)
+    }
+
+    /// Swaps the values of two cells.
+    pub fn swap(&mut self, a: &Coords, b: &Coords) {
+        if self.in_bounds(a.x, a.y) && self.in_bounds(b.x, b.y) {
+            let (a_index, b_index) = (self.index(a.x, a.y), self.index(b.x, b.y));
+            self.data.swap(a_index, b_index);
+        } else {
+            panic!(
+                "Swapping ({0}, {1}) and ({2}, {3}) in grid with bounds ({4}, {5})",
+                a.x, a.y, b.x, b.y, self.width, self.height
+            )
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    /// Cells of row `y` from west to east.
+    pub fn row_mut(&mut self, y: i32) -> &mut [T] {
+        if y >= 0 && y < self.height {
+            let start = self.index(0, y);
+            &mut self.data[start..start + self.width as usize]
+        } else {
+            panic!("Modifying row {y} of grid with height {0}", self.height)
+        }
+    }
+
+    /// Builds a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    pub fn try_move(&self, coords: &Coords, direction: &GridDirection) -> Option<Coords> {
+        let moved = *coords + direction.to_coords();
+        if self.in_bounds(moved.x, moved.y) {
+            Some(moved)
+        } else {
+            None
+        }
+    }
+
+    /// In-bounds horizontal and vertical neighbours of `coords`.
+    pub fn neighbors4(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        self.neighbors(coords, GridDirection::cardinal())
+    }
+
+    /// In-bounds neighbours of `coords` including the diagonal ones.
+    pub fn neighbors8(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        self.neighbors(coords, GridDirection::all())
+    }
+
+    fn neighbors(
+        &self,
+        coords: &Coords,
+        directions: impl Iterator<Item = GridDirection> + 'static,
+    ) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        let coords = *coords;
+        directions
+            .filter_map(move |direction| self.try_move(&coords, &direction))
+            .map(|neighbor| {
+                let value = self.get_by_coords(&neighbor);
+                (neighbor, value)
+            })
+    }
+
+    pub fn iter_all(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn enumerate_all(&self) -> GridEnumerator<'_, T> {
+        GridEnumerator {
+            view: self.full_view(),
+            current: 0
+        }
+    }
+
+    pub fn iter_all_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn enumerate_all_mut(&mut self) -> impl Iterator<Item = (i32, i32, &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, item)| (index as i32 % width, index as i32 / width, item))
+    }
+
+    pub fn iter(&self, from_x: i32, from_y: i32, direction: GridDirection) -> GridIterator<'_, T> {
+        if self.in_bounds(from_x, from_y) {
+            GridIterator {
+                view: self.full_view(),
+                current: Coords {
+                    x: from_x,
+                    y: from_y,
+                },
+                change: direction.to_coords(),
+                wrap_start: None,
+                started: false,
+            }
+        } else {
+            panic!(
+                "Iterating grid with bounds ({0}, {1}) at ({from_x}, {from_y})",
+                self.width, self.height
+            )
+        }
+    }
+
+    pub fn view(&self, from_x: i32, from_y: i32, width: i32, height: i32) -> GridView<'_, T> {
+        if self.in_bounds(from_x, from_y) && self.in_bounds(from_x + width - 1, from_y + height - 1)
+        {
+            GridView {
+                cells: &self.data,
+                stride: self.width,
+                grid_x: from_x,
+                grid_y: from_y,
+                width,
+                height,
+            }
+        } else {
+            panic!(
+                "Creating grid view at ({from_x}, {from_y}) with size ({width}, {height}) for grid with bounds ({0}, {1})",
+                self.width, self.height
+            )
+        }
+    }
+
+    /// Every `width` by `height` view of the grid in row-major order, with its origin.
+    pub fn windows(
+        &self,
+        width: i32,
+        height: i32,
+    ) -> impl Iterator<Item = (Coords, GridView<'_, T>)> {
+        self.full_view().windows(width, height)
+    }
+
+    pub fn full_view(&self) -> GridView<'_, T> {
+        GridView {
+            cells: &self.data,
+            stride: self.width,
+            grid_x: 0,
+            grid_y: 0,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> usize {
+        (y * self.width + x) as usize
+    }
+}
+
+impl<T: Clone + fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let idx = self.index(x, y);
+                write!(f, "{}", self.data[idx])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn check_dimensions(width: usize, height: usize) {
+    if width > i32::MAX.try_into().unwrap() {
+        panic!("Invalid grid width {width}")
+    }
+
+    if height > i32::MAX.try_into().unwrap() {
+        panic!("Invalid grid height {height}")
+    }
+}
+
+#[derive(Clone)]
+pub struct GridView<'a, T> {
+    /// Row-major cells of the underlying grid, with `stride` cells per row.
+    cells: &'a [T],
+    stride: i32,
+    grid_x: i32,
+    grid_y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl<'a, T: Clone> GridView<'a, T> {
+    pub fn get(&self, x: i32, y: i32) -> Option<&'a T> {
+        if self.in_bounds(x, y) {
+            let index = (self.grid_y + y) * self.stride + self.grid_x + x;
+            self.cells.get(index as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    /// Cells of the view in row-major order with coordinates relative to the view.
+    pub fn enumerate(&self) -> GridEnumerator<'a, T> {
+        GridEnumerator {
+            view: self.clone(),
+            current: 0,
+        }
+    }
+
+    /// Nested view with its origin at `(from_x, from_y)` of this view.
+    pub fn view(&self, from_x: i32, from_y: i32, width: i32, height: i32) -> GridView<'a, T> {
+        if self.in_bounds(from_x, from_y) && self.in_bounds(from_x + width - 1, from_y + height - 1)
+        {
+            GridView {
+                cells: self.cells,
+                stride: self.stride,
+                grid_x: self.grid_x + from_x,
+                grid_y: self.grid_y + from_y,
+                width,
+                height,
+            }
+        } else {
+            panic!(
+                "Creating grid view at ({from_x}, {from_y}) with size ({width}, {height}) for view with bounds ({0}, {1})",
+                self.width, self.height
+            )
+        }
+    }
+
+    /// Every `width` by `height` view inside this one in row-major order, with its origin
+    /// relative to this view. There are none when either size is not positive.
+    pub fn windows(
+        &self,
+        width: i32,
+        height: i32,
+    ) -> impl Iterator<Item = (Coords, GridView<'a, T>)> + 'a {
+        let view = self.clone();
+        let last_x = self.width - width;
+        let last_y = if width > 0 && height > 0 {
+            self.height - height
+        } else {
+            -1
+        };
+
+        (0..=last_y)
+            .flat_map(move |y| (0..=last_x).map(move |x| Coords { x, y }))
+            .map(move |origin| (origin, view.view(origin.x, origin.y, width, height)))
+    }
+
+    /// In-bounds horizontal and vertical neighbours of `coords`, relative to the view.
+    pub fn neighbors4(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &'a T)> + '_ {
+        self.neighbors(coords, GridDirection::cardinal())
+    }
+
+    /// In-bounds neighbours of `coords` including the diagonal ones, relative to the view.
+    pub fn neighbors8(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &'a T)> + '_ {
+        self.neighbors(coords, GridDirection::all())
+    }
+
+    fn neighbors(
+        &self,
+        coords: &Coords,
+        directions: impl Iterator<Item = GridDirection> + 'static,
+    ) -> impl Iterator<Item = (Coords, &'a T)> + '_ {
+        let coords = *coords;
+        directions.filter_map(move |direction| {
+            let neighbor = coords + direction.to_coords();
+            self.get(neighbor.x, neighbor.y).map(|value| (neighbor, value))
+        })
+    }
+
+    pub fn iter(&self, from_x: i32, from_y: i32, direction: GridDirection) -> GridIterator<'_, T> {
+        if self.in_bounds(from_x, from_y) {
+            GridIterator {
+                view: self.clone(),
+                current: Coords {
+                    x: from_x,
+                    y: from_y,
+                },
+                change: direction.to_coords(),
+                wrap_start: None,
+                started: false,
+            }
+        } else {
+            panic!(
+                "Iterating grid with bounds ({0}, {1}) at ({from_x}, {from_y})",
+                self.width, self.height
+            )
+        }
+    }
+}
+
+pub struct GridIterator<'a, T> {
+    view: GridView<'a, T>,
+    current: Coords,
+    change: Coords,
+    /// Start of an iterator that wraps around the edges, ending when the start is reached again.
+    wrap_start: Option<Coords>,
+    started: bool,
+}
+
+impl<'a, T: Clone> Iterator for GridIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started && Some(self.current) == self.wrap_start {
+            return None;
+        }
+
+        if self.view.in_bounds(self.current.x, self.current.y) {
+            let item = self.view.get(self.current.x, self.current.y);
+            self.started = true;
+            self.current = self.current + self.change;
+            if self.wrap_start.is_some() {
+                self.current = Coords {
+                    x: self.current.x.rem_euclid(self.view.width),
+                    y: self.current.y.rem_euclid(self.view.height),
+                };
+            }
+            item
+        } else {
+            None
+        }
+    }
+}
+
+pub struct GridEnumerator<'a, T> {
+    view: GridView<'a, T>,
+    current: usize
+}
+
+impl<'a, T: Clone> Iterator for GridEnumerator<'a, T> {
+    type Item = (i32, i32, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= (self.view.width * self.view.height) as usize {
+            return None;
+        }
+        
+        let x = (self.current as i32) % self.view.width;
+        let y = (self.current as i32) / self.view.width;
+
+        if let Some(item) = self.view.get(x, y) {
+            self.current += 1;
+            Some((x, y, item))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> Grid<i32> {
+        let lines = (0..height)
+            .map(|y| (0..width).map(|x| (y * width + x) as i32).collect())
+            .collect();
+        Grid::from_lines(lines)
+    }
+
+    #[test]
+    fn mutate_cells() {
+        let mut grid = numbered(3, 2);
+
+        *grid.get_mut(1, 0) = 10;
+        *grid.try_get_mut(2, 1).unwrap() += 100;
+        assert!(grid.try_get_mut(3, 0).is_none());
+        grid.swap(&Coords { x: 0, y: 0 }, &Coords { x: 0, y: 1 });
+        grid.row_mut(1).reverse();
+
+        assert_eq!(grid.iter_all().copied().collect::<Vec<_>>(), vec![3, 10, 2, 105, 4, 0]);
+
+        for (x, y, value) in grid.enumerate_all_mut() {
+            *value = x * 10 + y;
+        }
+        assert_eq!(grid.to_string(), "01020\n11121\n");
+
+        let doubled = grid.map(|v| v * 2);
+        assert_eq!(doubled.iter_all().copied().collect::<Vec<_>>(), vec![0, 20, 40, 2, 22, 42]);
+
+        grid.fill(7);
+        assert!(grid.iter_all_mut().all(|v| *v == 7));
+    }
+
+    #[test]
+    fn empty_lines_make_empty_grid() {
+        let grid: Grid<i32> = Grid::from_lines(Vec::new());
+
+        assert_eq!((grid.width, grid.height), (0, 0));
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn windows_and_nested_views() {
+        let grid = numbered(4, 3);
+
+        let windows: Vec<(Coords, i32)> = grid
+            .windows(3, 2)
+            .map(|(origin, view)| (origin, view.enumerate().map(|(_, _, v)| *v).sum()))
+            .collect();
+        assert_eq!(
+            windows,
+            vec![
+                (Coords { x: 0, y: 0 }, 18),
+                (Coords { x: 1, y: 0 }, 24),
+                (Coords { x: 0, y: 1 }, 42),
+                (Coords { x: 1, y: 1 }, 48),
+            ]
+        );
+        assert_eq!(grid.windows(5, 1).count(), 0);
+        assert_eq!(grid.windows(0, 2).count(), 0);
+        assert_eq!(grid.windows(2, 0).count(), 0);
+
+        let nested = grid.view(1, 1, 3, 2).view(1, 0, 2, 2);
+        let cells: Vec<(i32, i32, i32)> = nested.enumerate().map(|(x, y, v)| (x, y, *v)).collect();
+        assert_eq!(cells, vec![(0, 0, 6), (1, 0, 7), (0, 1, 10), (1, 1, 11)]);
+    }
+
+    #[test]
+    fn direction_sets() {
+        assert_eq!(GridDirection::all().count(), 8);
+        assert!(GridDirection::cardinal().all(|d| GridDirection::all().any(|a| a == d)));
+        assert!(GridDirection::diagonal().all(|d| !GridDirection::cardinal().any(|c| c == d)));
+    }
+
+    #[test]
+    fn coords_arithmetic() {
+        let a = Coords { x: 2, y: -3 };
+        let b = Coords { x: -1, y: 4 };
+
+        assert_eq!(a + b, Coords { x: 1, y: 1 });
+        assert_eq!(a - b, Coords { x: 3, y: -7 });
+        assert_eq!(-a * 2, Coords { x: -4, y: 6 });
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 7);
+    }
+
+    #[test]
+    fn direction_turns() {
+        use GridDirection::*;
+
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(NorthEast.turn_left(), NorthWest);
+        assert_eq!(SouthWest.opposite(), NorthEast);
+        assert_eq!(East.rotate_right_45(), SouthEast);
+        assert_eq!(North.rotate_left_45(), NorthWest);
+        assert!(GridDirection::all().all(|d| d.to_coords() == -d.opposite().to_coords()));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = numbered(3, 3);
+        let corner = Coords { x: 0, y: 0 };
+        let center = Coords { x: 1, y: 1 };
+
+        let values = |n: Vec<(Coords, &i32)>| n.into_iter().map(|(_, v)| *v).collect::<Vec<_>>();
+
+        assert_eq!(values(grid.neighbors4(&corner).collect()), vec![1, 3]);
+        assert_eq!(values(grid.neighbors8(&corner).collect()), vec![1, 4, 3]);
+        assert_eq!(values(grid.neighbors4(&center).collect()), vec![1, 5, 7, 3]);
+        assert_eq!(grid.neighbors8(&center).count(), 8);
+    }
+
+    #[test]
+    fn view_neighbors_are_relative() {
+        let grid = numbered(4, 4);
+        let view = grid.view(1, 1, 2, 2);
+
+        let neighbors: Vec<(i32, i32, i32)> = view
+            .neighbors8(&Coords { x: 0, y: 0 })
+            .map(|(c, v)| (c.x, c.y, *v))
+            .collect();
+
+        assert_eq!(neighbors, vec![(1, 0, 6), (1, 1, 10), (0, 1, 9)]);
+    }
+}
@@ -0,0 +1,149 @@
+use super::{Coords, Grid};
+
+/// Transformation of a pattern: an optional left-to-right flip followed by clockwise quarter turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub flipped: bool,
+    pub quarter_turns: u8,
+}
+
+impl Orientation {
+    pub const IDENTITY: Orientation = Orientation {
+        flipped: false,
+        quarter_turns: 0,
+    };
+
+    fn apply<T: Clone>(&self, grid: &Grid<T>) -> Grid<T> {
+        let mut result = if self.flipped {
+            grid.flip_horizontal()
+        } else {
+            grid.clone()
+        };
+
+        for _ in 0..self.quarter_turns {
+            result = result.rotate_cw();
+        }
+
+        result
+    }
+}
+
+/// Position and orientation of a pattern found in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    /// Top-left corner of the matching area.
+    pub origin: Coords,
+    pub orientation: Orientation,
+}
+
+/// Small grid to search for, where cells equal to the wildcard match anything.
+#[derive(Debug, Clone)]
+pub struct Pattern<T> {
+    variants: Vec<(Orientation, Grid<T>)>,
+    wildcard: T,
+}
+
+impl<T: Clone + PartialEq> Pattern<T> {
+    pub fn new(pattern: Grid<T>, wildcard: T) -> Self {
+        Pattern {
+            variants: vec![(Orientation::IDENTITY, pattern)],
+            wildcard,
+        }
+    }
+
+    /// Also matches all rotations and reflections of the pattern.
+    /// Orientations that produce the same cells are searched only once.
+    pub fn with_symmetries(mut self) -> Self {
+        let (_, pattern) = self.variants.swap_remove(0);
+
+        for flipped in [false, true] {
+            for quarter_turns in 0..4 {
+                let orientation = Orientation {
+                    flipped,
+                    quarter_turns,
+                };
+                let variant = orientation.apply(&pattern);
+
+                if self.variants.iter().all(|(_, known)| *known != variant) {
+                    self.variants.push((orientation, variant));
+                }
+            }
+        }
+
+        self
+    }
+
+    fn matches_at(&self, grid: &Grid<T>, origin: &Coords, variant: &Grid<T>) -> bool {
+        variant.enumerate_all().all(|(x, y, expected)| {
+            *expected == self.wildcard || grid.get(origin.x + x, origin.y + y) == expected
+        })
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// All occurrences of `pattern` in row-major order of their origin.
+    pub fn find_pattern(&self, pattern: &Pattern<T>) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for (orientation, variant) in &pattern.variants {
+            for (origin, _) in self.windows(variant.width, variant.height) {
+                if pattern.matches_at(self, &origin, variant) {
+                    matches.push(PatternMatch {
+                        origin,
+                        orientation: *orientation,
+                    });
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| (m.origin.y, m.origin.x));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    #[test]
+    fn find_with_wildcards() {
+        let haystack: Grid<char> = grid("ab.\nxbc\n").unwrap();
+        let pattern = Pattern::new(grid("b?\n?c\n").unwrap(), '?');
+
+        assert_eq!(
+            haystack.find_pattern(&pattern),
+            vec![PatternMatch {
+                origin: Coords { x: 1, y: 0 },
+                orientation: Orientation::IDENTITY,
+            }]
+        );
+    }
+
+    #[test]
+    fn find_in_all_orientations() {
+        let haystack: Grid<char> = grid("ab..\n..ba\n").unwrap();
+        let pattern = Pattern::new(grid("ab\n").unwrap(), '?').with_symmetries();
+
+        let matches = haystack.find_pattern(&pattern);
+
+        assert_eq!(pattern.variants.len(), 4);
+        assert_eq!(
+            matches,
+            vec![
+                PatternMatch {
+                    origin: Coords { x: 0, y: 0 },
+                    orientation: Orientation::IDENTITY,
+                },
+                PatternMatch {
+                    origin: Coords { x: 2, y: 1 },
+                    orientation: Orientation {
+                        flipped: false,
+                        quarter_turns: 2,
+                    },
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+
+use super::{Coords, Grid, GridDirection};
+
+/// Which neighbours of a cell belong to the same region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Horizontal and vertical neighbours.
+    Four,
+    /// Horizontal, vertical and diagonal neighbours.
+    Eight,
+}
+
+/// Index of a region, displayed as a single letter or digit so that label grids line up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionLabel(pub usize);
+
+const LABEL_GLYPHS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+impl fmt::Display for RegionLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = LABEL_GLYPHS[self.0 % LABEL_GLYPHS.len()];
+        write!(f, "{}", glyph as char)
+    }
+}
+
+/// Connected group of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: RegionLabel,
+    /// Cells of the region in row-major order.
+    pub cells: Vec<Coords>,
+    /// Number of cell edges bordering another region or the outside of the grid.
+    pub perimeter: usize,
+    /// Number of distinct straight sides of the border, equal to the number of its corners.
+    pub sides: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Regions of a grid together with a grid of the same size holding the label of every cell.
+#[derive(Clone)]
+pub struct Regions {
+    pub labels: Grid<RegionLabel>,
+    pub regions: Vec<Region>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Labels connected components whose neighbouring cells satisfy `same_region`.
+    /// Regions are numbered in row-major order of their first cell.
+    pub fn regions(
+        &self,
+        connectivity: Connectivity,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let mut labels: Grid<Option<RegionLabel>> =
+            Grid::from_default(self.width as usize, self.height as usize, None);
+        let mut regions = Vec::new();
+
+        for (x, y, _) in self.enumerate_all() {
+            if labels.get(x, y).is_some() {
+                continue;
+            }
+
+            let label = RegionLabel(regions.len());
+            let start = Coords { x, y };
+            labels.set_by_coords(&start, Some(label));
+
+            let mut cells = Vec::new();
+            let mut stack = vec![start];
+
+            while let Some(coords) = stack.pop() {
+                let value = self.get_by_coords(&coords);
+                let neighbors: Vec<(Coords, &T)> = match connectivity {
+                    Connectivity::Four => self.neighbors4(&coords).collect(),
+                    Connectivity::Eight => self.neighbors8(&coords).collect(),
+                };
+
+                for (neighbor, neighbor_value) in neighbors {
+                    if labels.get_by_coords(&neighbor).is_none()
+                        && same_region(value, neighbor_value)
+                    {
+                        labels.set_by_coords(&neighbor, Some(label));
+                        stack.push(neighbor);
+                    }
+                }
+
+                cells.push(coords);
+            }
+
+            cells.sort_by_key(|c| (c.y, c.x));
+            regions.push(Region {
+                label,
+                cells,
+                perimeter: 0,
+                sides: 0,
+            });
+        }
+
+        let labels = Grid::from_lines(
+            (0..self.height)
+                .map(|y| (0..self.width).map(|x| labels.get(x, y).unwrap()).collect())
+                .collect(),
+        );
+
+        for region in &mut regions {
+            region.perimeter = region.cells.iter().map(|c| border_edges(&labels, c)).sum();
+            region.sides = region.cells.iter().map(|c| corners(&labels, c)).sum();
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+fn same_label(labels: &Grid<RegionLabel>, coords: &Coords, direction: &GridDirection) -> bool {
+    let neighbor = *coords + direction.to_coords();
+    labels.try_get(neighbor.x, neighbor.y) == Some(labels.get_by_coords(coords))
+}
+
+fn border_edges(labels: &Grid<RegionLabel>, coords: &Coords) -> usize {
+    GridDirection::cardinal()
+        .filter(|direction| !same_label(labels, coords, direction))
+        .count()
+}
+
+/// Counts the corners of the region border at the cell, outer and inner ones.
+fn corners(labels: &Grid<RegionLabel>, coords: &Coords) -> usize {
+    use GridDirection::*;
+
+    [
+        (North, East, NorthEast),
+        (East, South, SouthEast),
+        (South, West, SouthWest),
+        (West, North, NorthWest),
+    ]
+    .iter()
+    .filter(|(first, second, diagonal)| {
+        let first = same_label(labels, coords, first);
+        let second = same_label(labels, coords, second);
+        let diagonal = same_label(labels, coords, diagonal);
+        (!first && !second) || (first && second && !diagonal)
+    })
+    .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    #[test]
+    fn measure_regions() {
+        let garden: Grid<char> = grid("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap();
+
+        let regions = garden.regions(Connectivity::Four, |a, b| a == b);
+        let measures: Vec<(usize, usize, usize)> = regions
+            .regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter, r.sides))
+            .collect();
+
+        assert_eq!(
+            measures,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions.labels.to_string(), "AAAA\nBBCD\nBBCC\nEEEC\n");
+    }
+
+    #[test]
+    fn inner_corners_and_diagonal_connectivity() {
+        let garden: Grid<char> = grid("XXX\nXOX\nXXX\n").unwrap();
+        let ring = &garden.regions(Connectivity::Four, |a, b| a == b).regions[0];
+
+        assert_eq!((ring.area(), ring.perimeter, ring.sides), (8, 16, 8));
+
+        let checkers: Grid<char> = grid("X.\n.X\n").unwrap();
+        assert_eq!(
+            checkers
+                .regions(Connectivity::Four, |a, b| a == b)
+                .regions
+                .len(),
+            4
+        );
+        assert_eq!(
+            checkers
+                .regions(Connectivity::Eight, |a, b| a == b)
+                .regions
+                .len(),
+            2
+        );
+    }
+}
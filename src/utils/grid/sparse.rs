@@ -0,0 +1,250 @@
+use std::{collections::HashMap, fmt};
+
+use super::{Coords, GridDirection};
+
+/// Smallest rectangle containing a set of coordinates, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Coords,
+    pub max: Coords,
+}
+
+impl Bounds {
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, coords: &Coords) -> bool {
+        (self.min.x..=self.max.x).contains(&coords.x)
+            && (self.min.y..=self.max.y).contains(&coords.y)
+    }
+
+    fn including(self, coords: &Coords) -> Bounds {
+        Bounds {
+            min: Coords {
+                x: self.min.x.min(coords.x),
+                y: self.min.y.min(coords.y),
+            },
+            max: Coords {
+                x: self.max.x.max(coords.x),
+                y: self.max.y.max(coords.y),
+            },
+        }
+    }
+}
+
+/// Unbounded grid storing only the cells that were set, at any coordinates including negative ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coords, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.cells.get(&Coords { x, y })
+    }
+
+    pub fn get_by_coords(&self, coords: &Coords) -> Option<&T> {
+        self.cells.get(coords)
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.cells.get_mut(&Coords { x, y })
+    }
+
+    /// Sets a cell, growing the bounding box when needed. Returns the previous value.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Option<T> {
+        self.set_by_coords(&Coords { x, y }, value)
+    }
+
+    pub fn set_by_coords(&mut self, coords: &Coords, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(coords),
+            None => Bounds {
+                min: *coords,
+                max: *coords,
+            },
+        });
+        self.cells.insert(*coords, value)
+    }
+
+    /// Clears a cell, shrinking the bounding box when needed. Returns the previous value.
+    pub fn remove(&mut self, coords: &Coords) -> Option<T> {
+        let removed = self.cells.remove(coords)?;
+
+        let on_border = self.bounds.is_some_and(|b| {
+            coords.x == b.min.x || coords.x == b.max.x || coords.y == b.min.y || coords.y == b.max.y
+        });
+        if on_border {
+            self.bounds = self.cells.keys().fold(None, |bounds, coords| match bounds {
+                Some(bounds) => Some(Bounds::including(bounds, coords)),
+                None => Some(Bounds {
+                    min: *coords,
+                    max: *coords,
+                }),
+            });
+        }
+
+        Some(removed)
+    }
+
+    pub fn contains(&self, coords: &Coords) -> bool {
+        self.cells.contains_key(coords)
+    }
+
+    /// Number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Bounding box of the cells that are set, `None` when there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Values of the cells that are set, in no particular order.
+    pub fn iter_all(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// Cells that are set with their coordinates, in no particular order.
+    pub fn enumerate_all(&self) -> impl Iterator<Item = (i32, i32, &T)> {
+        self.cells
+            .iter()
+            .map(|(coords, value)| (coords.x, coords.y, value))
+    }
+
+    /// Horizontal and vertical neighbours of `coords` that are set.
+    pub fn neighbors4(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        self.neighbors(coords, GridDirection::cardinal())
+    }
+
+    /// Neighbours of `coords` that are set, including the diagonal ones.
+    pub fn neighbors8(&self, coords: &Coords) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        self.neighbors(coords, GridDirection::all())
+    }
+
+    fn neighbors(
+        &self,
+        coords: &Coords,
+        directions: impl Iterator<Item = GridDirection> + 'static,
+    ) -> impl Iterator<Item = (Coords, &T)> + '_ {
+        let coords = *coords;
+        directions.filter_map(move |direction| {
+            let neighbor = coords + direction.to_coords();
+            self.get_by_coords(&neighbor).map(|value| (neighbor, value))
+        })
+    }
+}
+
+impl<T> FromIterator<(Coords, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coords, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (coords, value) in iter {
+            grid.set_by_coords(&coords, value);
+        }
+        grid
+    }
+}
+
+/// Renders the bounding box, with `.` for cells that are not set.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                match self.get(x, y) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_in_all_directions() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(0, 0, '#');
+        grid.set(-2, 1, 'a');
+        assert_eq!(grid.set(0, 0, 'b'), Some('#'));
+
+        assert_eq!(grid.get(-2, 1), Some(&'a'));
+        assert_eq!(grid.get(-1, 1), None);
+        assert_eq!(grid.len(), 2);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Coords { x: -2, y: 0 },
+                max: Coords { x: 0, y: 1 },
+            })
+        );
+        assert_eq!(grid.to_string(), "..b\na..\n");
+    }
+
+    #[test]
+    fn remove_shrinks_bounds() {
+        let mut grid: SparseGrid<u8> = [
+            (Coords { x: 0, y: 0 }, 1),
+            (Coords { x: 5, y: -3 }, 2),
+            (Coords { x: 1, y: 1 }, 3),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(grid.remove(&Coords { x: 5, y: -3 }), Some(2));
+        assert_eq!(grid.bounds().map(|b| (b.width(), b.height())), Some((2, 2)));
+        assert_eq!(grid.remove(&Coords { x: 5, y: -3 }), None);
+    }
+
+    #[test]
+    fn neighbors_are_populated_cells() {
+        let grid: SparseGrid<u8> = [
+            (Coords { x: -1, y: -1 }, 1),
+            (Coords { x: 0, y: -1 }, 2),
+            (Coords { x: 7, y: 7 }, 3),
+        ]
+        .into_iter()
+        .collect();
+        let origin = Coords { x: 0, y: 0 };
+
+        assert_eq!(
+            grid.neighbors4(&origin).collect::<Vec<_>>(),
+            vec![(Coords { x: 0, y: -1 }, &2)]
+        );
+        assert_eq!(grid.neighbors8(&origin).count(), 2);
+    }
+}
@@ -0,0 +1,154 @@
+//! Wrapping variants of the grid operations, treating the grid as a torus.
+
+use super::{Coords, Grid, GridDirection, GridIterator};
+
+impl<T: Clone> Grid<T> {
+    /// Wraps `coords` into the grid, so that leaving one edge enters at the opposite one.
+    pub fn wrap(&self, coords: &Coords) -> Coords {
+        Coords {
+            x: coords.x.rem_euclid(self.width),
+            y: coords.y.rem_euclid(self.height),
+        }
+    }
+
+    /// Moves by an arbitrary `offset`, like a velocity times a number of steps, wrapping around.
+    pub fn wrapping_offset(&self, coords: &Coords, offset: Coords) -> Coords {
+        self.wrap(&(*coords + offset))
+    }
+
+    /// Moves one step in `direction`, wrapping at the edges.
+    pub fn wrapping_move(&self, coords: &Coords, direction: &GridDirection) -> Coords {
+        self.wrapping_offset(coords, direction.to_coords())
+    }
+
+    /// Iterates in `direction` around the torus until returning to the start.
+    pub fn iter_wrapping(
+        &self,
+        from_x: i32,
+        from_y: i32,
+        direction: GridDirection,
+    ) -> GridIterator<'_, T> {
+        let mut iterator = self.iter(from_x, from_y, direction);
+        iterator.wrap_start = Some(Coords {
+            x: from_x,
+            y: from_y,
+        });
+        iterator
+    }
+
+    /// Index of the quadrant containing the wrapped `coords`: north-west, north-east, south-west
+    /// and south-east. Cells on the middle row or column of an odd-sized grid are in none.
+    pub fn quadrant(&self, coords: &Coords) -> Option<usize> {
+        let coords = self.wrap(coords);
+        let half = |position: i32, size: i32| {
+            if size % 2 == 1 && position == size / 2 {
+                None
+            } else {
+                Some(usize::from(position >= (size + 1) / 2))
+            }
+        };
+
+        Some(half(coords.y, self.height)? * 2 + half(coords.x, self.width)?)
+    }
+
+    /// Sums `count` over the cells of each quadrant, in the order of [`Grid::quadrant`].
+    pub fn count_per_quadrant(&self, count: impl Fn(&T) -> usize) -> [usize; 4] {
+        let mut counts = [0; 4];
+
+        for (x, y, value) in self.enumerate_all() {
+            if let Some(quadrant) = self.quadrant(&Coords { x, y }) {
+                counts[quadrant] += count(value);
+            }
+        }
+
+        counts
+    }
+}
+
+impl Grid<usize> {
+    /// Counts how many of `positions` land on each cell of a `width` by `height` torus.
+    pub fn tally_wrapped(
+        width: usize,
+        height: usize,
+        positions: impl IntoIterator<Item = Coords>,
+    ) -> Self {
+        let mut tally = Grid::from_default(width, height, 0);
+
+        for position in positions {
+            let cell = tally.wrap(&position);
+            *tally.get_mut_by_coords(&cell) += 1;
+        }
+
+        tally
+    }
+
+    /// Renders a tally with `.` for empty cells, the count up to 9 and `+` above.
+    pub fn render_tally(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.rows() {
+            for (_, &count) in row {
+                output.push(match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(count as u32, 10).unwrap(),
+                    _ => '+',
+                });
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_wrap_around() {
+        let grid = Grid::from_default(11, 7, 0usize);
+        let start = Coords { x: 2, y: 4 };
+
+        assert_eq!(
+            grid.wrapping_offset(&start, Coords { x: 2, y: -3 } * 5),
+            Coords { x: 1, y: 3 }
+        );
+        assert_eq!(
+            grid.wrapping_move(&Coords { x: 0, y: 0 }, &GridDirection::NorthWest),
+            Coords { x: 10, y: 6 }
+        );
+    }
+
+    #[test]
+    fn wrapping_iterator_visits_cycle_once() {
+        let grid = Grid::from_lines(vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        let east: Vec<i32> = grid
+            .iter_wrapping(1, 1, GridDirection::East)
+            .copied()
+            .collect();
+        let diagonal: Vec<i32> = grid
+            .iter_wrapping(0, 0, GridDirection::SouthEast)
+            .copied()
+            .collect();
+
+        assert_eq!(east, vec![4, 5, 3]);
+        assert_eq!(diagonal, vec![0, 4, 2, 3, 1, 5]);
+    }
+
+    #[test]
+    fn quadrants_skip_middle_lines() {
+        let robots =
+            [(0, 0), (6, 3), (9, 0), (-1, -1), (3, 5), (5, 2)].map(|(x, y)| Coords { x, y });
+
+        let tally = Grid::tally_wrapped(11, 7, robots);
+
+        assert_eq!(tally.quadrant(&Coords { x: 5, y: 0 }), None);
+        assert_eq!(tally.count_per_quadrant(|&count| count), [1, 1, 1, 1]);
+        assert_eq!(
+            tally.render_tally(),
+            "1........1.\n...........\n.....1.....\n......1....\n...........\n...1.......\n..........1\n"
+        );
+    }
+}
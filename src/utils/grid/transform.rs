@@ -0,0 +1,114 @@
+use super::{check_dimensions, Grid, GridView};
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid with the value of every cell given by `f(x, y)`.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(i32, i32) -> T) -> Self {
+        check_dimensions(width, height);
+        let (width, height) = (width as i32, height as i32);
+
+        Grid {
+            width,
+            height,
+            data: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+        }
+    }
+
+    /// Mirrors the grid along the diagonal from the top-left corner, swapping rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Rotates the grid by 90° counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// Copies the cells of `view` into a new grid.
+    pub fn crop(view: &GridView<'_, T>) -> Self {
+        Grid::from_fn(view.width as usize, view.height as usize, |x, y| {
+            view.get(x, y).unwrap().clone()
+        })
+    }
+
+    /// Surrounds the grid with a border of `n` cells set to `value`.
+    pub fn pad(&self, n: usize, value: T) -> Self {
+        let offset = n as i32;
+
+        Grid::from_fn(
+            self.width as usize + 2 * n,
+            self.height as usize + 2 * n,
+            |x, y| {
+                self.try_get(x - offset, y - offset)
+                    .cloned()
+                    .unwrap_or_else(|| value.clone())
+            },
+        )
+    }
+
+    /// Builds a `width` by `height` grid whose cell `(x, y)` is taken from `source(x, y)` here.
+    fn remap(&self, width: i32, height: i32, source: impl Fn(i32, i32) -> (i32, i32)) -> Self {
+        Grid::from_fn(width as usize, height as usize, |x, y| {
+            let (source_x, source_y) = source(x, y);
+            self.get(source_x, source_y).clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    fn letters(text: &str) -> Grid<char> {
+        grid(text).unwrap()
+    }
+
+    #[test]
+    fn rotate_and_transpose() {
+        let original = letters("abc\ndef\n");
+
+        assert_eq!(original.transpose(), letters("ad\nbe\ncf\n"));
+        assert_eq!(original.rotate_cw(), letters("da\neb\nfc\n"));
+        assert_eq!(original.rotate_ccw(), letters("cf\nbe\nad\n"));
+        assert_eq!(original.rotate_cw().rotate_ccw(), original);
+        assert_eq!(
+            original.rotate_cw().rotate_cw(),
+            original.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn flip() {
+        let original = letters("abc\ndef\n");
+
+        assert_eq!(original.flip_horizontal(), letters("cba\nfed\n"));
+        assert_eq!(original.flip_vertical(), letters("def\nabc\n"));
+    }
+
+    #[test]
+    fn crop_and_pad() {
+        let original = letters("abc\ndef\nghi\n");
+
+        assert_eq!(Grid::crop(&original.view(1, 1, 2, 2)), letters("ef\nhi\n"));
+        assert_eq!(letters("ab\n").pad(1, '.'), letters("....\n.ab.\n....\n"));
+    }
+}
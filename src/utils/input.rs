@@ -0,0 +1,148 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a directory with `dayNN/*.txt` input files.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Inputs compiled into the binary by `build.rs` when the `embed-inputs` feature is enabled.
+static EMBEDDED: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// Resolves relative input paths like `day01/input.txt` at runtime.
+///
+/// Directories are searched in this order:
+/// 1. the explicitly given directory (`--inputs-dir`),
+/// 2. the directory in [`INPUTS_DIR_VAR`],
+/// 3. `inputs` next to the executable,
+/// 4. the crate's `src` directory, so that `cargo run` and `cargo test` work out of the box.
+///
+/// Inputs embedded into the binary are used only when none of the directories contains the file.
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    dirs: Vec<PathBuf>,
+}
+
+impl InputResolver {
+    pub fn new(inputs_dir: Option<PathBuf>) -> Self {
+        let mut dirs = Vec::new();
+        dirs.extend(inputs_dir);
+        dirs.extend(env::var_os(INPUTS_DIR_VAR).map(PathBuf::from));
+
+        if let Some(exe_dir) = env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        {
+            dirs.push(exe_dir.join("inputs"));
+        }
+
+        dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+
+        InputResolver { dirs }
+    }
+
+    pub fn search_dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    pub fn read(&self, relative_path: &str) -> io::Result<String> {
+        for dir in &self.dirs {
+            let path = dir.join(relative_path);
+            if path.is_file() {
+                return fs::read_to_string(path);
+            }
+        }
+
+        if let Some((_, content)) = EMBEDDED.iter().find(|(key, _)| *key == relative_path) {
+            return Ok(content.to_string());
+        }
+
+        let searched = self
+            .dirs
+            .iter()
+            .map(|dir| dir.display().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{relative_path} not found in {searched}"),
+        ))
+    }
+}
+
+impl Default for InputResolver {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Normalizes puzzle input before parsing.
+///
+/// Converts CRLF line endings, drops blank lines at the start and end, removes the indentation
+/// shared by all non-blank lines and ends the text with a single newline. Trailing whitespace
+/// on the remaining lines is kept. Empty input stays empty.
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    let is_blank = |line: &&str| line.trim().is_empty();
+
+    let Some(first) = lines.iter().position(|line| !is_blank(line)) else {
+        return String::new();
+    };
+    let last = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .unwrap_or(first);
+    let lines = &lines[first..=last];
+
+    let indent = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| format!("{}\n", line.get(indent..).unwrap_or("")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_dir_takes_precedence() {
+        let dir = env::temp_dir().join(format!("aoc2024-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day01")).unwrap();
+        fs::write(dir.join("day01/input.txt"), "3   4\n").unwrap();
+
+        let resolver = InputResolver::new(Some(dir.clone()));
+        let content = resolver.read("day01/input.txt");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(content.unwrap(), "3   4\n");
+    }
+
+    #[test]
+    fn normalize_input() {
+        assert_eq!(
+            normalize("\r\n  a: 1\r\n\r\n    b\r\n  c  \r\n\n"),
+            "a: 1\n\n  b\nc  \n"
+        );
+        assert_eq!(normalize("  \n#. \n## \n  \n"), "#. \n## \n");
+        assert_eq!(normalize("1 2\n3 4"), "1 2\n3 4\n");
+        assert_eq!(normalize(" \n\n"), "");
+    }
+
+    #[test]
+    fn falls_back_to_source_dir() {
+        let resolver = InputResolver::new(None);
+        assert!(resolver.read("day03/test1.txt").is_ok());
+        assert!(resolver.read("day03/missing.txt").is_err());
+    }
+}
@@ -1,15 +1,25 @@
 pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod search;
 
+use input::InputResolver;
 use once_cell::sync::Lazy;
-use std::{fs, path::PathBuf};
+use std::io;
 
-static OUT_DIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from(env!("OUT_DIR")));
+static INPUTS: Lazy<InputResolver> = Lazy::new(InputResolver::default);
 
+/// Reads and [normalizes](input::normalize) a bundled input file.
 pub fn read_input_file(relative_path: &str) -> String {
-    fs::read_to_string(OUT_DIR.join(relative_path)).expect("Error reading file")
+    try_read_input_file(relative_path).expect("Error reading file")
 }
 
-pub fn drop_element(input: &Vec<i32>, index: usize) -> Vec<i32> {
+pub fn try_read_input_file(relative_path: &str) -> io::Result<String> {
+    INPUTS.read(relative_path).map(|input| input::normalize(&input))
+}
+
+pub fn drop_element(input: &[i32], index: usize) -> Vec<i32> {
     input
         .iter()
         .take(index)
@@ -0,0 +1,350 @@
+//! Parsing helpers for the recurring input shapes.
+//!
+//! Helpers that can fail take the whole puzzle `input` next to the fragment being parsed,
+//! so that errors can point at the right line and column. The fragment must be a subslice
+//! of the input, like the ones returned by [`str::lines`] or [`sections`].
+
+use std::{error::Error, fmt, str::FromStr};
+
+use super::grid::Grid;
+
+/// Error produced by malformed puzzle input, pointing at a 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error from 0-based line and column indices.
+    pub fn at(line_index: usize, column_index: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line: line_index + 1,
+            column: column_index + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error pointing at `fragment`, which must be a subslice of `input`.
+    pub fn at_fragment(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "Fragment is not part of the input");
+        let offset = offset.min(input.len());
+
+        let before = &input[..offset];
+        let line_index = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column_index = input[line_start..offset].chars().count();
+
+        ParseError::at(line_index, column_index, message)
+    }
+
+    /// Creates an error pointing just after the last character of `input`, for problems like
+    /// missing sections that have no position of their own.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        let line_index = input.lines().count().saturating_sub(1);
+        let column_index = input.lines().last().map_or(0, |line| line.chars().count());
+
+        ParseError::at(line_index, column_index, message)
+    }
+
+    /// Renders the error together with the offending line of `input` and a caret under the column.
+    pub fn render(&self, input: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let caret = format!("{}^", " ".repeat(self.column - 1));
+
+        format!("{self}\n{gutter} |\n{number} | {source_line}\n{gutter} | {caret}")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a subslice of `input`, reporting its position on failure.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at_fragment(input, token, format!("invalid value `{token}`")))
+}
+
+/// Extracts all signed integers from `fragment`, like `-3` and `14` in `p=-3,14`.
+/// Any other text is skipped, so use [`whitespace_list`] when it must be rejected.
+pub fn ints<T: FromStr>(input: &str, fragment: &str) -> Result<Vec<T>, ParseError> {
+    number_tokens(fragment, true)
+        .map(|token| parse_token(input, token))
+        .collect()
+}
+
+/// Extracts all unsigned integers from `fragment`, treating `-` as a separator.
+/// Any other text is skipped, so use [`whitespace_list`] when it must be rejected.
+pub fn uints<T: FromStr>(input: &str, fragment: &str) -> Result<Vec<T>, ParseError> {
+    number_tokens(fragment, false)
+        .map(|token| parse_token(input, token))
+        .collect()
+}
+
+fn number_tokens(fragment: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = fragment.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() {
+            let starts_negative = signed
+                && bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+
+            if bytes[index].is_ascii_digit() || starts_negative {
+                let start = index;
+                index += 1;
+                while index < bytes.len() && bytes[index].is_ascii_digit() {
+                    index += 1;
+                }
+                return Some(&fragment[start..index]);
+            }
+
+            index += 1;
+        }
+
+        None
+    })
+}
+
+/// Splits `input` into sections separated by blank lines.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(section_start) = start.take() {
+                sections.push(&input[section_start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(section_start) = start {
+        sections.push(&input[section_start..end]);
+    }
+
+    sections
+}
+
+/// Parses non-empty lines of `block` in the form `key: value`, trimming both sides.
+pub fn key_values<'a>(input: &str, block: &'a str) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    block
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split_once(':')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| ParseError::at_fragment(input, line, "expected `key: value`"))
+        })
+        .collect()
+}
+
+/// Parses a comma-separated list like `0,3,5,4`.
+pub fn comma_list<T: FromStr>(input: &str, fragment: &str) -> Result<Vec<T>, ParseError> {
+    fragment
+        .split(',')
+        .map(|token| parse_token(input, token.trim()))
+        .collect()
+}
+
+/// Parses every whitespace-separated token of `fragment`, like `7 6 4 2 1`.
+pub fn whitespace_list<T: FromStr>(input: &str, fragment: &str) -> Result<Vec<T>, ParseError> {
+    fragment
+        .split_whitespace()
+        .map(|token| parse_token(input, token))
+        .collect()
+}
+
+/// Grid cell that can be parsed from a single character.
+pub trait ParseCell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl ParseCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// Builds a grid from the lines of `input`, mapping every character through [`ParseCell`].
+pub fn grid<T: ParseCell + Clone>(input: &str) -> Result<Grid<T>, ParseError> {
+    grid_with(input, |_, _, c| {
+        T::from_char(c).ok_or_else(|| format!("unsupported cell `{c}`"))
+    })
+}
+
+/// Builds a grid from the lines of `input`, mapping every character with its `x` and `y` coordinates.
+pub fn grid_with<T: Clone>(
+    input: &str,
+    mut cell: impl FnMut(usize, usize, char) -> Result<T, String>,
+) -> Result<Grid<T>, ParseError> {
+    let lines = input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| cell(x, y, c).map_err(|message| ParseError::at(y, x, message)))
+                .collect::<Result<Vec<T>, _>>()
+        })
+        .collect::<Result<Vec<Vec<T>>, _>>()?;
+
+    let Some(width) = lines.first().map(Vec::len) else {
+        return Err(ParseError::at(0, 0, "expected a grid"));
+    };
+
+    if let Some(y) = lines.iter().position(|line| line.len() != width) {
+        let length = lines[y].len();
+        return Err(ParseError::at(
+            y,
+            length.min(width),
+            format!("expected {width} cells in every line, found {length}"),
+        ));
+    }
+
+    Ok(Grid::from_lines(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_points_at_fragment() {
+        let input = "1 2\n3 x4\n";
+        let fragment = &input[6..8];
+
+        let error = ParseError::at_fragment(input, fragment, "invalid value `x4`");
+
+        assert_eq!(error, ParseError::at(1, 2, "invalid value `x4`"));
+        assert_eq!(
+            error.render(input),
+            "line 2, column 3: invalid value `x4`\n  |\n2 | 3 x4\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn error_points_at_end() {
+        let input = "1 2\n3 4\n";
+
+        assert_eq!(
+            ParseError::at_end(input, "missing").render(input),
+            "line 2, column 4: missing\n  |\n2 | 3 4\n  |    ^"
+        );
+        assert_eq!(
+            ParseError::at_end("", "missing"),
+            ParseError::at(0, 0, "missing")
+        );
+    }
+
+    #[test]
+    fn extract_integers() {
+        let line = "p=-3,14 v=2-5";
+
+        assert_eq!(ints::<i32>(line, line), Ok(vec![-3, 14, 2, -5]));
+        assert_eq!(uints::<u32>(line, line), Ok(vec![3, 14, 2, 5]));
+
+        let overflowing = "x=300";
+        assert_eq!(
+            uints::<u8>(overflowing, overflowing),
+            Err(ParseError::at(0, 2, "invalid value `300`"))
+        );
+    }
+
+    #[test]
+    fn split_sections() {
+        let input = "\na\nb\n\n\nc\n  \nd";
+
+        assert_eq!(sections(input), vec!["a\nb", "c", "d"]);
+        assert_eq!(sections(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn parse_key_values_and_lists() {
+        let input = "Register A: 729\n\nProgram: 0,1,5\nBroken";
+        let (block, _) = input.split_at(input.len() - 7);
+
+        assert_eq!(
+            key_values(input, block),
+            Ok(vec![("Register A", "729"), ("Program", "0,1,5")])
+        );
+        assert_eq!(comma_list::<u8>(input, &input[26..31]), Ok(vec![0, 1, 5]));
+        assert_eq!(whitespace_list::<u8>("1  2 3", "1  2 3"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            whitespace_list::<u8>("1 x 3", "1 x 3"),
+            Err(ParseError::at(0, 2, "invalid value `x`"))
+        );
+        assert_eq!(
+            key_values(input, input).err(),
+            Some(ParseError::at(3, 0, "expected `key: value`"))
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl ParseCell for Cell {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Cell::Open),
+                '#' => Some(Cell::Wall),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn parse_grid() {
+        let parsed = grid::<Cell>(".#\n#.").unwrap();
+
+        assert_eq!((parsed.width, parsed.height), (2, 2));
+        assert_eq!(parsed.get(1, 0), &Cell::Wall);
+        assert_eq!(
+            grid::<Cell>(".#\n#x").err(),
+            Some(ParseError::at(1, 1, "unsupported cell `x`"))
+        );
+        assert_eq!(
+            grid::<Cell>(".#.\n#.\n").err(),
+            Some(ParseError::at(
+                1,
+                2,
+                "expected 3 cells in every line, found 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_token_reports_position() {
+        let input = "10,x,30";
+
+        assert_eq!(parse_token::<u32>(input, &input[0..2]), Ok(10));
+        assert_eq!(
+            parse_token::<u32>(input, &input[3..4]),
+            Err(ParseError::at(0, 3, "invalid value `x`"))
+        );
+    }
+}
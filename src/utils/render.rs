@@ -0,0 +1,283 @@
+//! Drawing grids with overlays to the terminal or to PPM/PGM images.
+
+use std::{fs, io, path::Path};
+
+use super::grid::{Coords, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    fn ansi(&self) -> String {
+        match self {
+            Colour::Black => "\x1b[30m".to_string(),
+            Colour::Red => "\x1b[31m".to_string(),
+            Colour::Green => "\x1b[32m".to_string(),
+            Colour::Yellow => "\x1b[33m".to_string(),
+            Colour::Blue => "\x1b[34m".to_string(),
+            Colour::Magenta => "\x1b[35m".to_string(),
+            Colour::Cyan => "\x1b[36m".to_string(),
+            Colour::White => "\x1b[37m".to_string(),
+            Colour::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        }
+    }
+
+    fn rgb(&self) -> [u8; 3] {
+        match *self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [229, 229, 229],
+            Colour::Rgb(r, g, b) => [r, g, b],
+        }
+    }
+}
+
+/// Symbol of a cell with an optional colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+}
+
+impl Glyph {
+    pub fn plain(symbol: char) -> Self {
+        Glyph {
+            symbol,
+            colour: None,
+        }
+    }
+
+    pub fn coloured(symbol: char, colour: Colour) -> Self {
+        Glyph {
+            symbol,
+            colour: Some(colour),
+        }
+    }
+
+    /// Pixel colour in images. Uncoloured glyphs are black for `.` and space, white otherwise.
+    fn pixel(&self) -> [u8; 3] {
+        match (self.colour, self.symbol) {
+            (Some(colour), _) => colour.rgb(),
+            (None, '.' | ' ') => Colour::Black.rgb(),
+            (None, _) => Colour::White.rgb(),
+        }
+    }
+}
+
+/// Draws a grid through a cell-to-glyph mapping, with overlay layers on top.
+///
+/// Layers are drawn in the order they were added, so later layers cover earlier ones.
+/// Overlay cells outside of the grid are ignored.
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> Glyph + 'a>,
+    layers: Vec<Vec<(Coords, Glyph)>>,
+}
+
+impl<'a, T: Clone> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>, glyph: impl Fn(&T) -> Glyph + 'a) -> Self {
+        Renderer {
+            grid,
+            glyph: Box::new(glyph),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a layer drawing `glyph` on each of `cells`.
+    pub fn layer(&mut self, cells: impl IntoIterator<Item = Coords>, glyph: Glyph) {
+        self.layers
+            .push(cells.into_iter().map(|coords| (coords, glyph)).collect());
+    }
+
+    /// Adds a layer drawing the consecutive cells of `path` as arrows pointing to the next
+    /// cell, with the last cell repeating the last arrow.
+    pub fn path(&mut self, path: &[Coords], colour: Colour) {
+        let mut arrows: Vec<(Coords, Glyph)> = path
+            .windows(2)
+            .map(|pair| {
+                let step = pair[1] - pair[0];
+                let symbol = match (step.x.signum(), step.y.signum()) {
+                    (1, 0) => '>',
+                    (-1, 0) => '<',
+                    (0, 1) => 'v',
+                    (0, -1) => '^',
+                    _ => '*',
+                };
+                (pair[0], Glyph::coloured(symbol, colour))
+            })
+            .collect();
+
+        if let Some(&last) = path.last() {
+            let symbol = arrows.last().map_or('*', |(_, glyph)| glyph.symbol);
+            arrows.push((last, Glyph::coloured(symbol, colour)));
+        }
+
+        self.layers.push(arrows);
+    }
+
+    /// Draws the grid with all layers applied.
+    pub fn picture(&self) -> Picture {
+        let mut glyphs = self.grid.map(|value| (self.glyph)(value));
+
+        for layer in &self.layers {
+            for (coords, glyph) in layer {
+                if let Some(cell) = glyphs.try_get_mut(coords.x, coords.y) {
+                    *cell = *glyph;
+                }
+            }
+        }
+
+        Picture { glyphs }
+    }
+}
+
+/// Drawn glyphs of every cell, ready to be output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    glyphs: Grid<Glyph>,
+}
+
+impl Picture {
+    /// Renders the symbols without colours.
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.glyphs.rows() {
+            output.extend(row.map(|(_, glyph)| glyph.symbol));
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Renders the symbols with ANSI colour codes for terminals.
+    pub fn to_ansi(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.glyphs.rows() {
+            for (_, glyph) in row {
+                match glyph.colour {
+                    Some(colour) => {
+                        output.push_str(&colour.ansi());
+                        output.push(glyph.symbol);
+                        output.push_str("\x1b[0m");
+                    }
+                    None => output.push(glyph.symbol),
+                }
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Binary PPM image with every cell drawn as a `scale` by `scale` square.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        self.image("P6", scale, |rgb| rgb.to_vec())
+    }
+
+    /// Binary PGM image in greyscale, with every cell drawn as a `scale` by `scale` square.
+    pub fn to_pgm(&self, scale: usize) -> Vec<u8> {
+        self.image("P5", scale, |[r, g, b]| {
+            let luma = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+            vec![luma.round() as u8]
+        })
+    }
+
+    /// Writes a PGM image for paths ending in `.pgm` and a PPM image otherwise.
+    pub fn write_image(&self, path: &Path, scale: usize) -> io::Result<()> {
+        let image = match path.extension().and_then(|e| e.to_str()) {
+            Some("pgm") => self.to_pgm(scale),
+            _ => self.to_ppm(scale),
+        };
+        fs::write(path, image)
+    }
+
+    fn image(&self, magic: &str, scale: usize, pixel: impl Fn([u8; 3]) -> Vec<u8>) -> Vec<u8> {
+        let (width, height) = (
+            self.glyphs.width as usize * scale,
+            self.glyphs.height as usize * scale,
+        );
+        let mut image = format!("{magic}\n{width} {height}\n255\n").into_bytes();
+
+        for row in self.glyphs.rows() {
+            let line: Vec<u8> = row
+                .flat_map(|(_, glyph)| pixel(glyph.pixel()).repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                image.extend_from_slice(&line);
+            }
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    fn renderer(map: &Grid<char>) -> Renderer<'_, char> {
+        let mut renderer = Renderer::new(map, |&c| Glyph::plain(c));
+        renderer.layer(
+            [Coords { x: 1, y: 1 }, Coords { x: 9, y: 9 }],
+            Glyph::coloured('O', Colour::Red),
+        );
+        renderer
+    }
+
+    #[test]
+    fn layers_cover_the_grid() {
+        let map: Grid<char> = grid("..#\n...\n").unwrap();
+        let mut renderer = renderer(&map);
+        renderer.path(
+            &[
+                Coords { x: 0, y: 1 },
+                Coords { x: 0, y: 0 },
+                Coords { x: 1, y: 0 },
+            ],
+            Colour::Green,
+        );
+
+        let picture = renderer.picture();
+        assert_eq!(picture.to_text(), ">>#\n^O.\n");
+        assert_eq!(
+            picture.to_ansi().lines().nth(1),
+            Some("\x1b[32m^\x1b[0m\x1b[31mO\x1b[0m.")
+        );
+    }
+
+    #[test]
+    fn images() {
+        let map: Grid<char> = grid("..#\n...\n").unwrap();
+        let picture = renderer(&map).picture();
+
+        let ppm = picture.to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // The wall is drawn white at pixels (4, 0) and (5, 1).
+        assert_eq!(&ppm[header.len() + 4 * 3..][..3], &[229, 229, 229]);
+        assert_eq!(&ppm[header.len() + (6 + 5) * 3..][..3], &[229, 229, 229]);
+
+        let pgm = picture.to_pgm(1);
+        assert_eq!(pgm, b"P5\n3 2\n255\n\0\0\xe5\0\x60\0".to_vec());
+    }
+}
@@ -0,0 +1,337 @@
+//! Graph searches over any node type with a successor function.
+//!
+//! The searches stop once every shortest path to the first goal reached is known. A goal
+//! predicate that never matches explores everything reachable from the start.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use super::grid::{Coords, Grid, GridDirection};
+
+/// Distances from the start and the predecessors on all shortest paths.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    pub start: N,
+    /// The first goal reached, if any.
+    pub goal: Option<N>,
+    /// Distances of every node reached. Nodes at most as far as the goal are final, but when the
+    /// search stops at the goal, farther nodes can hold upper bounds that were never settled,
+    /// as can their paths.
+    pub distances: HashMap<N, u64>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn new(start: N) -> Self {
+        SearchResult {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            goal: None,
+            start,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// Records reaching `next` from `node` with the total `distance`.
+    /// Returns true when `next` has not been reached this fast before.
+    fn relax(&mut self, node: &N, next: N, distance: u64) -> bool {
+        match self.distances.get(&next) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(node) {
+                    predecessors.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+
+    /// One shortest path from the start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        let mut current = node;
+        while let Some(previous) = self.predecessors.get(current).and_then(|p| p.first()) {
+            path.push(previous.clone());
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// All shortest paths from the start to `node`, both included.
+    /// Requires positive step costs, as steps with cost 0 can form cycles of equal distance.
+    pub fn all_paths(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return Vec::new();
+        }
+
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![node.clone()]];
+
+        while let Some(path) = stack.pop() {
+            let last = path.last().unwrap();
+            match self.predecessors.get(last) {
+                Some(predecessors) => {
+                    for previous in predecessors {
+                        let mut longer = path.clone();
+                        longer.push(previous.clone());
+                        stack.push(longer);
+                    }
+                }
+                None => {
+                    let mut path = path;
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+
+        for next in successors(&node) {
+            if result.relax(&node, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's search over non-negative step costs.
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search. The `heuristic` must never overestimate the remaining cost and must be consistent.
+pub fn astar<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    // The heap holds indices into `nodes`, so that nodes don't need to be ordered.
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&nodes[0]), 0, 0))]);
+    let mut goal_distance = None;
+
+    while let Some(Reverse((estimate, distance, index))) = heap.pop() {
+        if goal_distance.is_some_and(|goal| estimate > goal) {
+            break;
+        }
+
+        let node = nodes[index].clone();
+        if result.distance(&node) != Some(distance) {
+            continue;
+        }
+        if goal_distance.is_none() && is_goal(&node) {
+            goal_distance = Some(distance);
+            result.goal = Some(node);
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            if result.relax(&node, next.clone(), next_distance) {
+                let estimate = next_distance + heuristic(&next);
+                heap.push(Reverse((estimate, next_distance, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+
+    result
+}
+
+fn grid_steps<'a, T: Clone>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T) -> bool + 'a,
+) -> impl FnMut(&Coords) -> Vec<Coords> + 'a {
+    move |coords| {
+        grid.neighbors4(coords)
+            .filter(|(_, value)| passable(value))
+            .map(|(neighbor, _)| neighbor)
+            .collect()
+    }
+}
+
+/// Breadth-first search on `grid` with horizontal and vertical moves onto `passable` cells.
+pub fn grid_bfs<T: Clone>(
+    grid: &Grid<T>,
+    start: &Coords,
+    goal: Option<&Coords>,
+    passable: impl Fn(&T) -> bool,
+) -> SearchResult<Coords> {
+    bfs(*start, grid_steps(grid, passable), |c| {
+        Some(c) == goal
+    })
+}
+
+/// Dijkstra's search on `grid`, where `cost` gives the cost of entering a cell or `None` for walls.
+pub fn grid_dijkstra<T: Clone>(
+    grid: &Grid<T>,
+    start: &Coords,
+    goal: Option<&Coords>,
+    cost: impl Fn(&T) -> Option<u64>,
+) -> SearchResult<Coords> {
+    dijkstra(
+        *start,
+        |coords| grid_costs(grid, coords, &cost),
+        |c| Some(c) == goal,
+    )
+}
+
+/// A* search on `grid` towards `goal` using the Manhattan distance as heuristic.
+/// Entering a cell costs `cost`, which must be at least 1 for passable cells.
+pub fn grid_astar<T: Clone>(
+    grid: &Grid<T>,
+    start: &Coords,
+    goal: &Coords,
+    cost: impl Fn(&T) -> Option<u64>,
+) -> SearchResult<Coords> {
+    astar(
+        *start,
+        |coords| grid_costs(grid, coords, &cost),
+        |c| c.manhattan(goal).into(),
+        |c| c == goal,
+    )
+}
+
+fn grid_costs<T: Clone>(
+    grid: &Grid<T>,
+    coords: &Coords,
+    cost: &impl Fn(&T) -> Option<u64>,
+) -> Vec<(Coords, u64)> {
+    GridDirection::cardinal()
+        .filter_map(|direction| grid.try_move(coords, &direction))
+        .filter_map(|next| cost(grid.get_by_coords(&next)).map(|c| (next, c)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse::grid;
+
+    use super::*;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn find(maze: &Grid<char>, cell: char) -> Coords {
+        maze.enumerate_all()
+            .find(|(_, _, &c)| c == cell)
+            .map(|(x, y, _)| Coords { x, y })
+            .unwrap()
+    }
+
+    #[test]
+    fn bfs_counts_steps_and_paths() {
+        let maze: Grid<char> = grid(MAZE).unwrap();
+        let (start, end) = (find(&maze, 'S'), find(&maze, 'E'));
+
+        let result = grid_bfs(&maze, &start, Some(&end), |&c| c != '#');
+
+        assert_eq!(result.goal.as_ref(), Some(&end));
+        assert_eq!(result.distance(&end), Some(5));
+        assert_eq!(result.path(&end).unwrap().len(), 6);
+        assert_eq!(result.all_paths(&end).len(), 3);
+        assert!(result
+            .all_paths(&end)
+            .iter()
+            .all(|path| path.first() == Some(&start) && path.last() == Some(&end)));
+    }
+
+    #[test]
+    fn bfs_without_goal_fills_distances() {
+        let maze: Grid<char> = grid(MAZE).unwrap();
+
+        let result = grid_bfs(&maze, &Coords { x: 0, y: 0 }, None, |&c| c != '#');
+
+        assert_eq!(result.goal, None);
+        assert_eq!(result.distances.len(), 10);
+        assert_eq!(result.distance(&Coords { x: 3, y: 0 }), None);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        let costs: Grid<char> = grid("1911\n1191\n9111\n").unwrap();
+        let (start, end) = (Coords { x: 0, y: 0 }, Coords { x: 3, y: 2 });
+        let cost = |c: &char| c.to_digit(10).map(u64::from);
+
+        let dijkstra = grid_dijkstra(&costs, &start, Some(&end), cost);
+        let astar = grid_astar(&costs, &start, &end, cost);
+
+        assert_eq!(dijkstra.distance(&end), Some(5));
+        assert_eq!(astar.distance(&end), Some(5));
+        assert_eq!(dijkstra.all_paths(&end), astar.all_paths(&end));
+    }
+
+    #[test]
+    fn search_over_custom_states() {
+        // Reach 10 from 1 by doubling (cost 1) or incrementing (cost 2).
+        let result = dijkstra(
+            1u32,
+            |&n| {
+                [(n * 2, 1), (n + 1, 2)]
+                    .into_iter()
+                    .filter(|&(m, _)| m <= 10)
+            },
+            |&n| n == 10,
+        );
+
+        assert_eq!(result.distance(&10), Some(5));
+        assert_eq!(result.path(&10), Some(vec![1, 2, 4, 5, 10]));
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crate::{
+    runner::{Align, Table},
+    solution::{Answer, Puzzle, Run},
+};
+
+/// Name of the answers file, resolved like the inputs.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Recorded correct answers keyed by day and part.
+///
+/// Every non-empty line has the form `<day> <part> <answer>`, lines starting with `#` are comments.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, u32), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let (Some(day), Some(part), Some(answer), None) =
+                (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+            else {
+                return Err(format!(
+                    "line {}: expected `<day> <part> <answer>`",
+                    index + 1
+                ));
+            };
+
+            let day = day
+                .parse()
+                .map_err(|_| format!("line {}: invalid day `{day}`", index + 1))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("line {}: invalid part `{part}`", index + 1)),
+            };
+
+            answers.insert((day, part), answer.to_string());
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Error { message: String },
+}
+
+struct Check {
+    day: u32,
+    /// `None` when the whole day failed.
+    part: Option<u32>,
+    actual: Option<Answer>,
+    status: Status,
+}
+
+/// Comparison of computed answers against the recorded ones.
+#[derive(Default)]
+pub struct Verification {
+    checks: Vec<Check>,
+}
+
+impl Verification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, puzzle: &dyn Puzzle, run: &Run, answers: &Answers) {
+        let parts = [(1, &run.part_one), (2, &run.part_two)];
+
+        for (part, part_run) in parts {
+            let Some(part_run) = part_run else { continue };
+            let status = match answers.get(puzzle.day(), part) {
+                None => Status::Missing,
+                Some(expected) if expected == part_run.answer.to_string() => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+            };
+
+            self.checks.push(Check {
+                day: puzzle.day(),
+                part: Some(part),
+                actual: Some(part_run.answer.clone()),
+                status,
+            });
+        }
+    }
+
+    /// Records that `day` could not be run, so that the other days can still be checked.
+    pub fn add_error(&mut self, day: u32, message: impl Into<String>) {
+        self.checks.push(Check {
+            day,
+            part: None,
+            actual: None,
+            status: Status::Error {
+                message: message.into(),
+            },
+        });
+    }
+
+    pub fn count(&self, status: fn(&Status) -> bool) -> usize {
+        self.checks.iter().filter(|c| status(&c.status)).count()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.count(|s| matches!(s, Status::Fail { .. } | Status::Error { .. })) == 0
+    }
+
+    pub fn render(&self) -> String {
+        let mut table = Table::new([
+            Align::Right,
+            Align::Right,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+        ]);
+        table.row(["Day", "Part", "Status", "Answer", "Expected"].map(String::from));
+        table.separator();
+
+        for check in &self.checks {
+            let (status, expected) = match &check.status {
+                Status::Pass => ("pass", String::new()),
+                Status::Fail { expected } => ("FAIL", expected.clone()),
+                Status::Missing => ("missing", String::new()),
+                // Parse errors span several lines, the table only shows the first.
+                Status::Error { message } => (
+                    "ERROR",
+                    message.lines().next().unwrap_or_default().to_string(),
+                ),
+            };
+
+            table.row([
+                check.day.to_string(),
+                check.part.map_or_else(String::new, |part| part.to_string()),
+                status.to_string(),
+                check
+                    .actual
+                    .as_ref()
+                    .map_or_else(String::new, Answer::to_string),
+                expected,
+            ]);
+        }
+
+        let mut output = table.render();
+        output.push_str(&format!(
+            "{} passed, {} failed, {} missing, {} errored\n",
+            self.count(|s| *s == Status::Pass),
+            self.count(|s| matches!(s, Status::Fail { .. })),
+            self.count(|s| *s == Status::Missing),
+            self.count(|s| matches!(s, Status::Error { .. })),
+        ));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse("# comment\n\n1 1 42\n17 2 7,3,0\n").unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("42"));
+        assert_eq!(answers.get(17, 2), Some("7,3,0"));
+        assert_eq!(answers.get(1, 2), None);
+    }
+
+    #[test]
+    fn parse_answers_reports_bad_line() {
+        assert_eq!(
+            Answers::parse("1 1 42\n1 3 5\n").unwrap_err(),
+            "line 2: invalid part `3`"
+        );
+        assert!(Answers::parse("1 1\n").is_err());
+    }
+
+    #[test]
+    fn errors_fail_verification() {
+        let mut verification = Verification::new();
+        assert!(verification.is_success());
+
+        verification.add_error(3, "Cannot read day03/input.txt");
+
+        assert!(!verification.is_success());
+        assert!(verification.render().ends_with(
+            "| Cannot read day03/input.txt\n0 passed, 0 failed, 0 missing, 1 errored\n"
+        ));
+    }
+}